@@ -0,0 +1,285 @@
+use std::collections::HashMap;
+use events::{EventKind, FoulType};
+use replay::{Replay, GameState, Side};
+use stats::{Stat, PlayByPlayEvent, EventPerson};
+
+
+/// Counting stats, for a player or a team.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BoxTotals {
+    pub pts: u64,
+    pub fgm: u64,
+    pub fga: u64,
+    pub fg3m: u64,
+    pub fg3a: u64,
+    pub ftm: u64,
+    pub fta: u64,
+    pub oreb: u64,
+    pub dreb: u64,
+    pub ast: u64,
+    pub stl: u64,
+    pub blk: u64,
+    pub tov: u64,
+    pub pf: u64,
+}
+
+impl BoxTotals {
+    pub fn reb(&self) -> u64 {
+        self.oreb + self.dreb
+    }
+
+    fn add(&mut self, other: &BoxTotals) {
+        self.pts += other.pts;
+        self.fgm += other.fgm;
+        self.fga += other.fga;
+        self.fg3m += other.fg3m;
+        self.fg3a += other.fg3a;
+        self.ftm += other.ftm;
+        self.fta += other.fta;
+        self.oreb += other.oreb;
+        self.dreb += other.dreb;
+        self.ast += other.ast;
+        self.stl += other.stl;
+        self.blk += other.blk;
+        self.tov += other.tov;
+        self.pf += other.pf;
+    }
+}
+
+/// A player's line, rebuilt from play-by-play.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayerBox {
+    pub player_id: String,
+    pub player_name: Option<String>,
+    pub team_id: Option<String>,
+    pub side: Side,
+    pub totals: BoxTotals,
+    /// Seconds on court, going by the replay's lineups.
+    pub seconds: u64,
+}
+
+impl PlayerBox {
+    pub fn minutes(&self) -> f64 {
+        self.seconds as f64 / 60.0
+    }
+}
+
+/// A team's line. `totals` sums the players' lines and adds turnovers charged to the team,
+/// while team rebounds are kept apart, as on the official box score.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TeamBox {
+    pub totals: BoxTotals,
+    pub team_rebounds: u64,
+}
+
+/// A box score rebuilt from a game's play-by-play.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoxScore {
+    /// Players in the order they first show up.
+    pub players: Vec<PlayerBox>,
+    pub home: TeamBox,
+    pub visitor: TeamBox,
+}
+
+impl BoxScore {
+    pub fn player(&self, player_id: &str) -> Option<&PlayerBox> {
+        self.players.iter().find(|x| x.player_id == player_id)
+    }
+
+    pub fn team(&self, side: Side) -> &TeamBox {
+        match side {
+            Side::Home => &self.home,
+            Side::Visitor => &self.visitor,
+        }
+    }
+
+    /// Builds the box score from a game's events.
+    pub fn from_events(events: &[PlayByPlayEvent]) -> BoxScore {
+        let mut builder = Builder {
+            players: Vec::new(),
+            index: HashMap::new(),
+            home: TeamBox::default(),
+            visitor: TeamBox::default(),
+        };
+        let mut before = GameState::new();
+        let mut last_elapsed = None;
+        for (event, state) in Replay::new(events) {
+            if let (Some(last), Some(now)) = (last_elapsed, event.elapsed()) {
+                if now > last {
+                    builder.play_time(&before, now - last);
+                }
+            }
+            if event.elapsed().is_some() {
+                last_elapsed = event.elapsed();
+            }
+            builder.count(event, &before);
+            before = state;
+        }
+
+        for player in &builder.players {
+            match player.side {
+                Side::Home => builder.home.totals.add(&player.totals),
+                Side::Visitor => builder.visitor.totals.add(&player.totals),
+            }
+        }
+        BoxScore {
+            players: builder.players,
+            home: builder.home,
+            visitor: builder.visitor,
+        }
+    }
+
+    /// Builds the box score from the `Stat::PlayByPlay` rows of a game, skipping any other
+    /// rows.
+    pub fn from_stats(stats: &[Stat]) -> BoxScore {
+        let events: Vec<PlayByPlayEvent> = stats.iter().filter_map(play_by_play).collect();
+        BoxScore::from_events(&events)
+    }
+}
+
+fn play_by_play(stat: &Stat) -> Option<PlayByPlayEvent> {
+    match *stat {
+        Stat::PlayByPlay(ref event) => Some(event.clone()),
+        _ => None,
+    }
+}
+
+
+struct Builder {
+    players: Vec<PlayerBox>,
+    index: HashMap<String, usize>,
+    home: TeamBox,
+    visitor: TeamBox,
+}
+
+impl Builder {
+    /// The line of a player named in an event, `None` for teams.
+    fn player(&mut self, person: &Option<EventPerson>) -> Option<&mut BoxTotals> {
+        let person = match *person {
+            Some(ref person) if !person.is_team() => person,
+            _ => return None,
+        };
+        let side = match person.side() {
+            Some(side) => side,
+            None => return None,
+        };
+        let idx = self.entry(&person.id, side);
+        let player = &mut self.players[idx];
+        if player.player_name.is_none() {
+            player.player_name = person.name.clone();
+        }
+        if player.team_id.is_none() {
+            player.team_id = person.team_id.clone();
+        }
+        Some(&mut player.totals)
+    }
+
+    fn entry(&mut self, player_id: &str, side: Side) -> usize {
+        if let Some(idx) = self.index.get(player_id) {
+            return *idx;
+        }
+        self.players.push(PlayerBox {
+            player_id: player_id.to_owned(),
+            player_name: None,
+            team_id: None,
+            side: side,
+            totals: BoxTotals::default(),
+            seconds: 0,
+        });
+        self.index.insert(player_id.to_owned(), self.players.len() - 1);
+        self.players.len() - 1
+    }
+
+    fn team(&mut self, side: Side) -> &mut TeamBox {
+        match side {
+            Side::Home => &mut self.home,
+            Side::Visitor => &mut self.visitor,
+        }
+    }
+
+    fn play_time(&mut self, state: &GameState, seconds: u64) {
+        for &(side, team) in &[(Side::Home, &state.home), (Side::Visitor, &state.visitor)] {
+            for id in &team.on_court {
+                let idx = self.entry(id, side);
+                self.players[idx].seconds += seconds;
+            }
+        }
+    }
+
+    /// Counts an event, given the game state just before it.
+    fn count(&mut self, event: &PlayByPlayEvent, before: &GameState) {
+        let kind = match event.kind() {
+            Some(kind) => kind,
+            None => return,
+        };
+        let three = event.details.three_point;
+        match kind {
+            EventKind::MadeShot(_) => {
+                if let Some(shooter) = self.player(&event.player1) {
+                    shooter.fgm += 1;
+                    shooter.fga += 1;
+                    shooter.pts += 2;
+                    if three {
+                        shooter.fg3m += 1;
+                        shooter.fg3a += 1;
+                        shooter.pts += 1;
+                    }
+                }
+                if let Some(assister) = self.player(&event.player2) {
+                    assister.ast += 1;
+                }
+            }
+            EventKind::MissedShot(_) => {
+                if let Some(shooter) = self.player(&event.player1) {
+                    shooter.fga += 1;
+                    if three {
+                        shooter.fg3a += 1;
+                    }
+                }
+                if let Some(blocker) = self.player(&event.player3) {
+                    blocker.blk += 1;
+                }
+            }
+            EventKind::FreeThrow(_) => {
+                if let Some(shooter) = self.player(&event.player1) {
+                    shooter.fta += 1;
+                    if !event.details.missed {
+                        shooter.ftm += 1;
+                        shooter.pts += 1;
+                    }
+                }
+            }
+            EventKind::Rebound => {
+                let side = event.player1.as_ref().and_then(|x| x.side());
+                let offensive = side.is_some() && side == before.possession;
+                let is_team = event.player1.as_ref().map(|x| x.is_team()).unwrap_or(false);
+                if let (true, Some(side)) = (is_team, side) {
+                    self.team(side).team_rebounds += 1;
+                } else if let Some(rebounder) = self.player(&event.player1) {
+                    if offensive {
+                        rebounder.oreb += 1;
+                    } else {
+                        rebounder.dreb += 1;
+                    }
+                }
+            }
+            EventKind::Turnover(_) => {
+                let team_turnover = event.player1.as_ref().map(|x| (x.is_team(), x.side()));
+                if let Some((true, Some(side))) = team_turnover {
+                    self.team(side).totals.tov += 1;
+                } else if let Some(player) = self.player(&event.player1) {
+                    player.tov += 1;
+                }
+                if let Some(stealer) = self.player(&event.player2) {
+                    stealer.stl += 1;
+                }
+            }
+            EventKind::Foul(foul) if foul != FoulType::Technical => {
+                if let Some(fouler) = self.player(&event.player1) {
+                    fouler.pf += 1;
+                }
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,526 @@
+use params::{GameId, Period, Season, GameDate, DayOffset, League, SeasonType, PerMode,
+             MeasureType};
+use queries::Query;
+use teams::Team;
+
+
+/// Builds a `PlayByPlayQuery`. Covers the whole game unless periods are given.
+#[derive(Debug, Clone)]
+pub struct PlayByPlayQueryBuilder {
+    game_id: GameId,
+    start_period: Period,
+    end_period: Period,
+}
+
+impl PlayByPlayQueryBuilder {
+    pub fn new(game_id: GameId) -> PlayByPlayQueryBuilder {
+        PlayByPlayQueryBuilder {
+            game_id: game_id,
+            start_period: Period::first(),
+            end_period: Period::last(),
+        }
+    }
+
+    pub fn start_period(mut self, period: Period) -> PlayByPlayQueryBuilder {
+        self.start_period = period;
+        self
+    }
+
+    pub fn end_period(mut self, period: Period) -> PlayByPlayQueryBuilder {
+        self.end_period = period;
+        self
+    }
+
+    pub fn build(self) -> Query {
+        Query::PlayByPlayQuery {
+            gameid: self.game_id.to_string(),
+            startperiod: self.start_period.to_string(),
+            endperiod: self.end_period.to_string(),
+        }
+    }
+}
+
+
+/// Builds the queries served by the scoreboard endpoint: game headers and
+/// both conferences' standings for a given day.
+#[derive(Debug, Clone)]
+pub struct ScoreboardQueryBuilder {
+    league: League,
+    game_date: GameDate,
+    day_offset: DayOffset,
+}
+
+impl ScoreboardQueryBuilder {
+    pub fn new(game_date: GameDate) -> ScoreboardQueryBuilder {
+        ScoreboardQueryBuilder {
+            league: League::NBA,
+            game_date: game_date,
+            day_offset: DayOffset::zero(),
+        }
+    }
+
+    pub fn league(mut self, league: League) -> ScoreboardQueryBuilder {
+        self.league = league;
+        self
+    }
+
+    pub fn day_offset(mut self, day_offset: DayOffset) -> ScoreboardQueryBuilder {
+        self.day_offset = day_offset;
+        self
+    }
+
+    pub fn game_header(&self) -> Query {
+        Query::GameHeaderQuery {
+            leagueid: self.league.to_string(),
+            gamedate: self.game_date.to_string(),
+            dayoffset: self.day_offset.to_string(),
+        }
+    }
+
+    pub fn east_conf_standings(&self) -> Query {
+        Query::EastConfStandingsQuery {
+            leagueid: self.league.to_string(),
+            gamedate: self.game_date.to_string(),
+            dayoffset: self.day_offset.to_string(),
+        }
+    }
+
+    pub fn west_conf_standings(&self) -> Query {
+        Query::WestConfStandingsQuery {
+            leagueid: self.league.to_string(),
+            gamedate: self.game_date.to_string(),
+            dayoffset: self.day_offset.to_string(),
+        }
+    }
+}
+
+
+/// Builds a `TeamRosterQuery`.
+#[derive(Debug, Clone)]
+pub struct TeamRosterQueryBuilder {
+    season: Season,
+    team_id: String,
+}
+
+impl TeamRosterQueryBuilder {
+    pub fn new(season: Season, team: &Team) -> TeamRosterQueryBuilder {
+        TeamRosterQueryBuilder {
+            season: season,
+            team_id: team.id.clone(),
+        }
+    }
+
+    pub fn build(self) -> Query {
+        Query::TeamRosterQuery {
+            season: self.season.to_string(),
+            teamid: self.team_id,
+        }
+    }
+}
+
+
+/// Builds the box score queries for a game. Covers the whole game unless periods are given.
+#[derive(Debug, Clone)]
+pub struct BoxScoreQueryBuilder {
+    game_id: GameId,
+    start_period: Period,
+    end_period: Period,
+}
+
+impl BoxScoreQueryBuilder {
+    pub fn new(game_id: GameId) -> BoxScoreQueryBuilder {
+        BoxScoreQueryBuilder {
+            game_id: game_id,
+            start_period: Period::first(),
+            end_period: Period::last(),
+        }
+    }
+
+    pub fn start_period(mut self, period: Period) -> BoxScoreQueryBuilder {
+        self.start_period = period;
+        self
+    }
+
+    pub fn end_period(mut self, period: Period) -> BoxScoreQueryBuilder {
+        self.end_period = period;
+        self
+    }
+
+    pub fn traditional(&self) -> Query {
+        Query::BoxScoreTraditionalQuery {
+            gameid: self.game_id.to_string(),
+            startperiod: self.start_period.to_string(),
+            endperiod: self.end_period.to_string(),
+            startrange: "0".to_owned(),
+            endrange: "0".to_owned(),
+            rangetype: "0".to_owned(),
+        }
+    }
+
+    pub fn advanced(&self) -> Query {
+        Query::BoxScoreAdvancedQuery {
+            gameid: self.game_id.to_string(),
+            startperiod: self.start_period.to_string(),
+            endperiod: self.end_period.to_string(),
+            startrange: "0".to_owned(),
+            endrange: "0".to_owned(),
+            rangetype: "0".to_owned(),
+        }
+    }
+
+    pub fn four_factors(&self) -> Query {
+        Query::BoxScoreFourFactorsQuery {
+            gameid: self.game_id.to_string(),
+            startperiod: self.start_period.to_string(),
+            endperiod: self.end_period.to_string(),
+            startrange: "0".to_owned(),
+            endrange: "0".to_owned(),
+            rangetype: "0".to_owned(),
+        }
+    }
+}
+
+
+/// Builds a `ShotChartDetailQuery`. Covers every shot of the season unless narrowed down
+/// to a team, player or game.
+#[derive(Debug, Clone)]
+pub struct ShotChartQueryBuilder {
+    league: League,
+    season: Season,
+    season_type: SeasonType,
+    team_id: String,
+    player_id: String,
+    game_id: Option<GameId>,
+}
+
+impl ShotChartQueryBuilder {
+    pub fn new(season: Season) -> ShotChartQueryBuilder {
+        ShotChartQueryBuilder {
+            league: League::NBA,
+            season: season,
+            season_type: SeasonType::RegularSeason,
+            team_id: "0".to_owned(),
+            player_id: "0".to_owned(),
+            game_id: None,
+        }
+    }
+
+    pub fn league(mut self, league: League) -> ShotChartQueryBuilder {
+        self.league = league;
+        self
+    }
+
+    pub fn season_type(mut self, season_type: SeasonType) -> ShotChartQueryBuilder {
+        self.season_type = season_type;
+        self
+    }
+
+    pub fn team(mut self, team: &Team) -> ShotChartQueryBuilder {
+        self.team_id = team.id.clone();
+        self
+    }
+
+    pub fn player(mut self, player_id: &str) -> ShotChartQueryBuilder {
+        self.player_id = player_id.to_owned();
+        self
+    }
+
+    pub fn game(mut self, game_id: GameId) -> ShotChartQueryBuilder {
+        self.game_id = Some(game_id);
+        self
+    }
+
+    pub fn build(self) -> Query {
+        Query::ShotChartDetailQuery {
+            leagueid: self.league.to_string(),
+            season: self.season.to_string(),
+            seasontype: self.season_type.to_string(),
+            teamid: self.team_id,
+            playerid: self.player_id,
+            gameid: self.game_id.map(|x| x.to_string()).unwrap_or(String::new()),
+        }
+    }
+}
+
+
+/// Builds the per-player queries: game logs, career stats and biographical info.
+#[derive(Debug, Clone)]
+pub struct PlayerQueryBuilder {
+    player_id: String,
+    league: League,
+    season_type: SeasonType,
+}
+
+impl PlayerQueryBuilder {
+    pub fn new(player_id: &str) -> PlayerQueryBuilder {
+        PlayerQueryBuilder {
+            player_id: player_id.to_owned(),
+            league: League::NBA,
+            season_type: SeasonType::RegularSeason,
+        }
+    }
+
+    pub fn league(mut self, league: League) -> PlayerQueryBuilder {
+        self.league = league;
+        self
+    }
+
+    pub fn season_type(mut self, season_type: SeasonType) -> PlayerQueryBuilder {
+        self.season_type = season_type;
+        self
+    }
+
+    pub fn game_log(&self, season: Season) -> Query {
+        Query::PlayerGameLogQuery {
+            playerid: self.player_id.clone(),
+            season: season.to_string(),
+            seasontype: self.season_type.to_string(),
+        }
+    }
+
+    /// Season by season and career totals, for both the regular season and the playoffs.
+    pub fn career_stats(&self) -> Query {
+        Query::PlayerCareerStatsQuery {
+            playerid: self.player_id.clone(),
+            permode: "Totals".to_owned(),
+            leagueid: self.league.to_string(),
+        }
+    }
+
+    pub fn info(&self) -> Query {
+        Query::CommonPlayerInfoQuery {
+            playerid: self.player_id.clone(),
+            leagueid: self.league.to_string(),
+        }
+    }
+}
+
+
+/// Builds a `TeamGameLogQuery`. Covers the regular season unless told otherwise.
+#[derive(Debug, Clone)]
+pub struct TeamGameLogQueryBuilder {
+    team_id: String,
+    season: Season,
+    season_type: SeasonType,
+}
+
+impl TeamGameLogQueryBuilder {
+    pub fn new(team: &Team, season: Season) -> TeamGameLogQueryBuilder {
+        TeamGameLogQueryBuilder {
+            team_id: team.id.clone(),
+            season: season,
+            season_type: SeasonType::RegularSeason,
+        }
+    }
+
+    pub fn season_type(mut self, season_type: SeasonType) -> TeamGameLogQueryBuilder {
+        self.season_type = season_type;
+        self
+    }
+
+    pub fn build(self) -> Query {
+        Query::TeamGameLogQuery {
+            teamid: self.team_id,
+            season: self.season.to_string(),
+            seasontype: self.season_type.to_string(),
+        }
+    }
+}
+
+
+/// Builds a `LeagueGameFinderQuery`. Finds every team's games in the season unless
+/// narrowed down to a team or a date range.
+#[derive(Debug, Clone)]
+pub struct LeagueGameFinderQueryBuilder {
+    league: League,
+    season: Season,
+    season_type: SeasonType,
+    team_id: Option<String>,
+    date_from: Option<GameDate>,
+    date_to: Option<GameDate>,
+}
+
+impl LeagueGameFinderQueryBuilder {
+    pub fn new(season: Season) -> LeagueGameFinderQueryBuilder {
+        LeagueGameFinderQueryBuilder {
+            league: League::NBA,
+            season: season,
+            season_type: SeasonType::RegularSeason,
+            team_id: None,
+            date_from: None,
+            date_to: None,
+        }
+    }
+
+    pub fn league(mut self, league: League) -> LeagueGameFinderQueryBuilder {
+        self.league = league;
+        self
+    }
+
+    pub fn season_type(mut self, season_type: SeasonType) -> LeagueGameFinderQueryBuilder {
+        self.season_type = season_type;
+        self
+    }
+
+    pub fn team(mut self, team: &Team) -> LeagueGameFinderQueryBuilder {
+        self.team_id = Some(team.id.clone());
+        self
+    }
+
+    pub fn date_from(mut self, date: GameDate) -> LeagueGameFinderQueryBuilder {
+        self.date_from = Some(date);
+        self
+    }
+
+    pub fn date_to(mut self, date: GameDate) -> LeagueGameFinderQueryBuilder {
+        self.date_to = Some(date);
+        self
+    }
+
+    pub fn build(self) -> Query {
+        Query::LeagueGameFinderQuery {
+            leagueid: self.league.to_string(),
+            season: self.season.to_string(),
+            seasontype: self.season_type.to_string(),
+            teamid: self.team_id.unwrap_or(String::new()),
+            datefrom: self.date_from.map(|x| x.to_string()).unwrap_or(String::new()),
+            dateto: self.date_to.map(|x| x.to_string()).unwrap_or(String::new()),
+        }
+    }
+}
+
+
+/// Builds a `LeagueLeadersQuery`, ranking by points per game unless told otherwise.
+#[derive(Debug, Clone)]
+pub struct LeagueLeadersQueryBuilder {
+    league: League,
+    season: Season,
+    season_type: SeasonType,
+    per_mode: PerMode,
+    stat_category: String,
+}
+
+impl LeagueLeadersQueryBuilder {
+    pub fn new(season: Season) -> LeagueLeadersQueryBuilder {
+        LeagueLeadersQueryBuilder {
+            league: League::NBA,
+            season: season,
+            season_type: SeasonType::RegularSeason,
+            per_mode: PerMode::PerGame,
+            stat_category: "PTS".to_owned(),
+        }
+    }
+
+    pub fn league(mut self, league: League) -> LeagueLeadersQueryBuilder {
+        self.league = league;
+        self
+    }
+
+    pub fn season_type(mut self, season_type: SeasonType) -> LeagueLeadersQueryBuilder {
+        self.season_type = season_type;
+        self
+    }
+
+    pub fn per_mode(mut self, per_mode: PerMode) -> LeagueLeadersQueryBuilder {
+        self.per_mode = per_mode;
+        self
+    }
+
+    /// The column to rank by, e.g. "REB" or "AST".
+    pub fn stat_category(mut self, stat_category: &str) -> LeagueLeadersQueryBuilder {
+        self.stat_category = stat_category.to_owned();
+        self
+    }
+
+    pub fn build(self) -> Query {
+        Query::LeagueLeadersQuery {
+            leagueid: self.league.to_string(),
+            season: self.season.to_string(),
+            seasontype: self.season_type.to_string(),
+            permode: self.per_mode.to_string(),
+            statcategory: self.stat_category,
+        }
+    }
+}
+
+
+/// Builds the league dashboard queries, for players or teams. Covers the whole season's
+/// basic per game numbers unless told otherwise.
+#[derive(Debug, Clone)]
+pub struct LeagueDashQueryBuilder {
+    league: League,
+    season: Season,
+    season_type: SeasonType,
+    per_mode: PerMode,
+    measure_type: MeasureType,
+    date_from: Option<GameDate>,
+    date_to: Option<GameDate>,
+}
+
+impl LeagueDashQueryBuilder {
+    pub fn new(season: Season) -> LeagueDashQueryBuilder {
+        LeagueDashQueryBuilder {
+            league: League::NBA,
+            season: season,
+            season_type: SeasonType::RegularSeason,
+            per_mode: PerMode::PerGame,
+            measure_type: MeasureType::Base,
+            date_from: None,
+            date_to: None,
+        }
+    }
+
+    pub fn league(mut self, league: League) -> LeagueDashQueryBuilder {
+        self.league = league;
+        self
+    }
+
+    pub fn season_type(mut self, season_type: SeasonType) -> LeagueDashQueryBuilder {
+        self.season_type = season_type;
+        self
+    }
+
+    pub fn per_mode(mut self, per_mode: PerMode) -> LeagueDashQueryBuilder {
+        self.per_mode = per_mode;
+        self
+    }
+
+    pub fn measure_type(mut self, measure_type: MeasureType) -> LeagueDashQueryBuilder {
+        self.measure_type = measure_type;
+        self
+    }
+
+    pub fn date_from(mut self, date: GameDate) -> LeagueDashQueryBuilder {
+        self.date_from = Some(date);
+        self
+    }
+
+    pub fn date_to(mut self, date: GameDate) -> LeagueDashQueryBuilder {
+        self.date_to = Some(date);
+        self
+    }
+
+    pub fn players(&self) -> Query {
+        Query::LeagueDashPlayerStatsQuery {
+            leagueid: self.league.to_string(),
+            season: self.season.to_string(),
+            seasontype: self.season_type.to_string(),
+            permode: self.per_mode.to_string(),
+            measuretype: self.measure_type.to_string(),
+            datefrom: self.date_from.map(|x| x.to_string()).unwrap_or(String::new()),
+            dateto: self.date_to.map(|x| x.to_string()).unwrap_or(String::new()),
+        }
+    }
+
+    pub fn teams(&self) -> Query {
+        Query::LeagueDashTeamStatsQuery {
+            leagueid: self.league.to_string(),
+            season: self.season.to_string(),
+            seasontype: self.season_type.to_string(),
+            permode: self.per_mode.to_string(),
+            measuretype: self.measure_type.to_string(),
+            datefrom: self.date_from.map(|x| x.to_string()).unwrap_or(String::new()),
+            dateto: self.date_to.map(|x| x.to_string()).unwrap_or(String::new()),
+        }
+    }
+}
@@ -0,0 +1,219 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::env;
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use serde_json;
+use serde_json::Value;
+use hyper::Url;
+use err::NBAError;
+use transport::{Transport, HyperTransport, Response};
+
+/// Set this environment variable to re-record cassettes against stats.nba.com.
+pub static RECORD_ENV_VAR: &'static str = "NBALIVE_RECORD";
+
+
+/// A single recorded request and the response it got back.
+#[derive(Debug, Clone)]
+pub struct Interaction {
+    pub url: String,
+    pub key: String,
+    pub status: u16,
+    pub body: String,
+}
+
+/// A set of recorded interactions, stored as one JSON file on disk.
+#[derive(Debug)]
+pub struct Cassette {
+    path: PathBuf,
+    interactions: Vec<Interaction>,
+}
+
+impl Cassette {
+    pub fn new<P: Into<PathBuf>>(path: P) -> Cassette {
+        Cassette {
+            path: path.into(),
+            interactions: Vec::new(),
+        }
+    }
+
+    pub fn load<P: Into<PathBuf>>(path: P) -> Result<Cassette, NBAError> {
+        let path = path.into();
+        let mut file = try!(File::open(&path));
+        let mut s = String::new();
+        try!(file.read_to_string(&mut s));
+
+        let data: Value = try!(serde_json::from_str(&s));
+        let data = try!(data.as_object().ok_or(NBAError::ObjectError));
+        let data = try!(data.get("interactions").ok_or(NBAError::MissingField("interactions")));
+        let data = try!(data.as_array().ok_or(NBAError::ArrayError));
+
+        let mut interactions = Vec::new();
+        for interaction in data {
+            let interaction = try!(interaction.as_object().ok_or(NBAError::ObjectError));
+            let url = try!(interaction.get("url")
+                                      .and_then(|x| x.as_string())
+                                      .ok_or(NBAError::MissingField("url")));
+            let status = try!(interaction.get("status")
+                                         .and_then(|x| x.as_u64())
+                                         .ok_or(NBAError::MissingField("status")));
+            let body = try!(interaction.get("body").ok_or(NBAError::MissingField("body")));
+            // Bodies are stored inline as JSON when possible, so cassettes stay readable.
+            let body = match *body {
+                Value::String(ref s) => s.clone(),
+                ref other => try!(serde_json::to_string(other)),
+            };
+            let parsed_url = try!(Url::parse(url));
+            interactions.push(Interaction {
+                url: url.to_owned(),
+                key: normalize(&parsed_url),
+                status: status as u16,
+                body: body,
+            });
+        }
+
+        Ok(Cassette {
+            path: path,
+            interactions: interactions,
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn interactions(&self) -> &[Interaction] {
+        &self.interactions
+    }
+
+    pub fn find(&self, url: &Url) -> Option<&Interaction> {
+        let key = normalize(url);
+        self.interactions.iter().find(|x| x.key == key)
+    }
+
+    /// Adds an interaction, replacing any earlier one for the same normalized query.
+    pub fn record(&mut self, url: &Url, response: &Response) {
+        let key = normalize(url);
+        self.interactions.retain(|x| x.key != key);
+        self.interactions.push(Interaction {
+            url: url.as_str().to_owned(),
+            key: key,
+            status: response.status,
+            body: response.body.clone(),
+        });
+    }
+
+    pub fn save(&self) -> Result<(), NBAError> {
+        let mut interactions = Vec::new();
+        for interaction in &self.interactions {
+            let body = match serde_json::from_str::<Value>(&interaction.body) {
+                Ok(value) => value,
+                Err(_) => Value::String(interaction.body.clone()),
+            };
+            let mut obj = BTreeMap::new();
+            obj.insert("url".to_owned(), Value::String(interaction.url.clone()));
+            obj.insert("status".to_owned(), Value::U64(interaction.status as u64));
+            obj.insert("body".to_owned(), body);
+            interactions.push(Value::Object(obj));
+        }
+        let mut data = BTreeMap::new();
+        data.insert("interactions".to_owned(), Value::Array(interactions));
+
+        let s = try!(serde_json::to_string_pretty(&Value::Object(data)));
+        let mut file = try!(File::create(&self.path));
+        try!(file.write_all(s.as_bytes()));
+        Ok(())
+    }
+}
+
+
+/// Normalizes a query url so that recorded and replayed requests match regardless of
+/// parameter order or key case, e.g. `playbyplay?endperiod=14&gameid=0041400106&startperiod=0`.
+pub fn normalize(url: &Url) -> String {
+    let endpoint = url.path()
+                      .split('/')
+                      .filter(|x| !x.is_empty())
+                      .last()
+                      .unwrap_or("")
+                      .to_lowercase();
+    let mut pairs: Vec<(String, String)> = url.query_pairs()
+                                              .map(|(k, v)| (k.to_lowercase(), v.into_owned()))
+                                              .collect();
+    pairs.sort();
+    let query: Vec<String> = pairs.iter().map(|&(ref k, ref v)| format!("{}={}", k, v)).collect();
+    format!("{}?{}", endpoint, query.join("&"))
+}
+
+
+/// Serves responses out of a cassette. Unrecorded queries are an error.
+pub struct ReplayTransport {
+    cassette: Cassette,
+}
+
+impl ReplayTransport {
+    pub fn new(cassette: Cassette) -> ReplayTransport {
+        ReplayTransport { cassette: cassette }
+    }
+
+    pub fn load<P: Into<PathBuf>>(path: P) -> Result<ReplayTransport, NBAError> {
+        Ok(ReplayTransport::new(try!(Cassette::load(path))))
+    }
+}
+
+impl Transport for ReplayTransport {
+    fn send(&self, url: &Url, _headers: &[(String, String)]) -> Result<Response, NBAError> {
+        self.cassette
+            .find(url)
+            .map(|x| {
+                Response {
+                    status: x.status,
+                    body: x.body.clone(),
+                }
+            })
+            .ok_or(NBAError::MissingResponse(normalize(url)))
+    }
+}
+
+
+/// Passes requests through to another transport and writes every response to a cassette.
+pub struct RecordingTransport<T: Transport> {
+    inner: T,
+    cassette: RefCell<Cassette>,
+}
+
+impl<T: Transport> RecordingTransport<T> {
+    /// Records into `path`, keeping whatever the cassette already holds.
+    pub fn new<P: Into<PathBuf>>(inner: T, path: P) -> RecordingTransport<T> {
+        let path = path.into();
+        let cassette = Cassette::load(path.clone()).unwrap_or(Cassette::new(path));
+        RecordingTransport {
+            inner: inner,
+            cassette: RefCell::new(cassette),
+        }
+    }
+
+    pub fn into_cassette(self) -> Cassette {
+        self.cassette.into_inner()
+    }
+}
+
+impl<T: Transport> Transport for RecordingTransport<T> {
+    fn send(&self, url: &Url, headers: &[(String, String)]) -> Result<Response, NBAError> {
+        let response = try!(self.inner.send(url, headers));
+        let mut cassette = self.cassette.borrow_mut();
+        cassette.record(url, &response);
+        try!(cassette.save());
+        Ok(response)
+    }
+}
+
+
+/// Replays the cassette at `path`, or records into it over hyper when `NBALIVE_RECORD` is set.
+pub fn from_env<P: Into<PathBuf>>(path: P) -> Result<Box<Transport>, NBAError> {
+    if env::var(RECORD_ENV_VAR).is_ok() {
+        Ok(Box::new(RecordingTransport::new(HyperTransport::new(), path)))
+    } else {
+        Ok(Box::new(try!(ReplayTransport::load(path))))
+    }
+}
@@ -0,0 +1,188 @@
+use std::cmp;
+use std::fmt;
+use serde_json::Value;
+use decode::FromValue;
+use params::GameDate;
+use stats::PlayByPlayEvent;
+
+
+/// Seconds in a regulation quarter.
+pub static QUARTER_SECONDS: u64 = 12 * 60;
+/// Seconds in an overtime period.
+pub static OVERTIME_SECONDS: u64 = 5 * 60;
+/// Seconds in a game that doesn't go to overtime.
+pub static REGULATION_SECONDS: u64 = 4 * 12 * 60;
+
+/// Length of a period in seconds. Periods 1-4 are quarters, 5 and up are overtimes.
+pub fn period_seconds(period: u64) -> u64 {
+    if period <= 4 {
+        QUARTER_SECONDS
+    } else {
+        OVERTIME_SECONDS
+    }
+}
+
+/// Game seconds elapsed when a period tips off.
+pub fn period_start(period: u64) -> u64 {
+    match period {
+        0 | 1 => 0,
+        2...5 => (period - 1) * QUARTER_SECONDS,
+        _ => REGULATION_SECONDS + (period - 5) * OVERTIME_SECONDS,
+    }
+}
+
+
+/// The time left in a period, from `PCTIMESTRING` values like "11:42". Tenths of a second,
+/// as in "0:04.5" or "4.5", are dropped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct GameClock {
+    remaining: u64,
+}
+
+impl GameClock {
+    pub fn new(minutes: u64, seconds: u64) -> GameClock {
+        GameClock { remaining: minutes * 60 + seconds }
+    }
+
+    pub fn parse(clock: &str) -> Option<GameClock> {
+        let clock = clock.trim();
+        let (minutes, seconds) = match clock.find(':') {
+            Some(idx) => (&clock[..idx], &clock[idx + 1..]),
+            None => ("0", clock),
+        };
+        let seconds = seconds.split('.').next().unwrap_or("");
+        match (minutes.parse::<u64>(), seconds.parse::<u64>()) {
+            (Ok(minutes), Ok(seconds)) if seconds < 60 => Some(GameClock::new(minutes, seconds)),
+            _ => None,
+        }
+    }
+
+    /// Seconds left in the period.
+    pub fn remaining(&self) -> u64 {
+        self.remaining
+    }
+
+    /// Game seconds elapsed at this point of `period`.
+    pub fn elapsed(&self, period: u64) -> u64 {
+        let length = period_seconds(period);
+        period_start(period) + length - cmp::min(self.remaining, length)
+    }
+
+    /// Seconds left in regulation, or in the current overtime.
+    pub fn game_remaining(&self, period: u64) -> u64 {
+        if period < 4 {
+            self.remaining + (4 - period) * QUARTER_SECONDS
+        } else {
+            self.remaining
+        }
+    }
+}
+
+impl FromValue for GameClock {
+    fn from_value(value: &Value) -> Option<GameClock> {
+        value.as_string().and_then(GameClock::parse)
+    }
+    fn expected() -> &'static str {
+        "a clock like \"11:42\""
+    }
+}
+
+impl fmt::Display for GameClock {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{:02}", self.remaining / 60, self.remaining % 60)
+    }
+}
+
+
+/// A time of day from `WCTIMESTRING` values like "7:45 PM", tied to the date of the game.
+///
+/// Times before 6 AM are read as the early hours after the game date, for late tip-offs
+/// that run past midnight, so wall clocks of one game always sort in order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct WallClock {
+    date: GameDate,
+    /// Minutes since midnight of `date`, past 1440 after midnight.
+    minutes: u64,
+}
+
+impl WallClock {
+    pub fn parse(time: &str, date: GameDate) -> Option<WallClock> {
+        let mut parts = time.split_whitespace();
+        let (clock, meridiem) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(clock), Some(meridiem), None) => (clock, meridiem),
+            _ => return None,
+        };
+        let mut clock = clock.split(':').map(|x| x.parse::<u64>());
+        let (hour, minute) = match (clock.next(), clock.next(), clock.next()) {
+            (Some(Ok(hour)), Some(Ok(minute)), None) if hour >= 1 && hour <= 12 &&
+                                                        minute < 60 => (hour, minute),
+            _ => return None,
+        };
+        let hour = match meridiem {
+            "AM" if hour == 12 => 0,
+            "AM" => hour,
+            "PM" if hour == 12 => 12,
+            "PM" => hour + 12,
+            _ => return None,
+        };
+        let hour = if hour < 6 { hour + 24 } else { hour };
+        Some(WallClock {
+            date: date,
+            minutes: hour * 60 + minute,
+        })
+    }
+
+    pub fn date(&self) -> GameDate {
+        self.date
+    }
+
+    /// Minutes since midnight of the game date.
+    pub fn minutes(&self) -> u64 {
+        self.minutes
+    }
+
+    /// Minutes from `earlier` to this time, `None` if `earlier` is later or on another date.
+    pub fn minutes_since(&self, earlier: &WallClock) -> Option<u64> {
+        if self.date == earlier.date && self.minutes >= earlier.minutes {
+            Some(self.minutes - earlier.minutes)
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for WallClock {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let hour = (self.minutes / 60) % 24;
+        let meridiem = if hour < 12 { "AM" } else { "PM" };
+        let hour = match hour % 12 {
+            0 => 12,
+            hour => hour,
+        };
+        write!(f, "{}:{:02} {}", hour, self.minutes % 60, meridiem)
+    }
+}
+
+
+impl PlayByPlayEvent {
+    /// Game seconds elapsed at this event, counting overtimes as five minutes.
+    pub fn elapsed(&self) -> Option<u64> {
+        match (self.period, self.pctimestring) {
+            (Some(period), Some(clock)) => Some(clock.elapsed(period)),
+            _ => None,
+        }
+    }
+
+    /// Seconds left in regulation, or in the current overtime.
+    pub fn time_left(&self) -> Option<u64> {
+        match (self.period, self.pctimestring) {
+            (Some(period), Some(clock)) => Some(clock.game_remaining(period)),
+            _ => None,
+        }
+    }
+
+    /// The time of day of the event, on a game played on `date`.
+    pub fn wall_clock(&self, date: GameDate) -> Option<WallClock> {
+        self.wctimestring.as_ref().and_then(|x| WallClock::parse(x, date))
+    }
+}
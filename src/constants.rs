@@ -8,3 +8,21 @@ pub static GAMEHEADER_BASE_URL: &'static str = "http://stats.nba.com/stats/score
 pub static EASTCONFSTANDINGS_BASE_URL: &'static str = "http://stats.nba.com/stats/scoreboard/";
 pub static WESTCONFSTANDINGS_BASE_URL: &'static str = "http://stats.nba.com/stats/scoreboard/";
 pub static TEAMROSTER_BASE_URL: &'static str = "http://stats.nba.com/stats/commonteamroster/";
+pub static BOXSCORETRADITIONAL_BASE_URL: &'static str =
+    "http://stats.nba.com/stats/boxscoretraditionalv2/";
+pub static BOXSCOREADVANCED_BASE_URL: &'static str =
+    "http://stats.nba.com/stats/boxscoreadvancedv2/";
+pub static BOXSCOREFOURFACTORS_BASE_URL: &'static str =
+    "http://stats.nba.com/stats/boxscorefourfactorsv2/";
+pub static SHOTCHART_BASE_URL: &'static str = "http://stats.nba.com/stats/shotchartdetail/";
+pub static PLAYERGAMELOG_BASE_URL: &'static str = "http://stats.nba.com/stats/playergamelog/";
+pub static PLAYERCAREERSTATS_BASE_URL: &'static str =
+    "http://stats.nba.com/stats/playercareerstats/";
+pub static COMMONPLAYERINFO_BASE_URL: &'static str = "http://stats.nba.com/stats/commonplayerinfo/";
+pub static TEAMGAMELOG_BASE_URL: &'static str = "http://stats.nba.com/stats/teamgamelog/";
+pub static LEAGUEGAMEFINDER_BASE_URL: &'static str = "http://stats.nba.com/stats/leaguegamefinder/";
+pub static LEAGUELEADERS_BASE_URL: &'static str = "http://stats.nba.com/stats/leagueleaders/";
+pub static LEAGUEDASHPLAYERSTATS_BASE_URL: &'static str =
+    "http://stats.nba.com/stats/leaguedashplayerstats/";
+pub static LEAGUEDASHTEAMSTATS_BASE_URL: &'static str =
+    "http://stats.nba.com/stats/leaguedashteamstats/";
@@ -0,0 +1,279 @@
+use std::collections::HashMap;
+use serde_json::Value;
+use err::NBAError;
+
+
+/// Implements `FromRow` for a struct by naming the column each field is read from.
+///
+/// ```ignore
+/// from_row!(RosterEntry {
+///     player => "PLAYER",
+///     player_id => "PLAYER_ID",
+/// });
+/// ```
+#[macro_export]
+macro_rules! from_row {
+    ($name:ident { $($field:ident => $column:expr),* $(,)* }) => {
+        impl $crate::decode::FromRow for $name {
+            fn from_row(row: &$crate::decode::Row) -> Result<$name, $crate::err::NBAError> {
+                Ok($name {
+                    $($field: try!(row.get($column)),)*
+                })
+            }
+        }
+    }
+}
+
+
+/// Converts a single JSON cell into a Rust value.
+///
+/// stats.nba.com is loose with its types: ids come back as numbers or strings, seasons as
+/// "2014", ages as 24.0, and empty clocks as blank strings. Conversions accept any of those
+/// spellings, and only fail when the value cannot mean what the field asks for.
+pub trait FromValue: Sized {
+    fn from_value(value: &Value) -> Option<Self>;
+    /// Describes the accepted values, for error messages.
+    fn expected() -> &'static str;
+}
+
+impl FromValue for String {
+    fn from_value(value: &Value) -> Option<String> {
+        match *value {
+            Value::String(ref s) => Some(s.clone()),
+            Value::I64(n) => Some(n.to_string()),
+            Value::U64(n) => Some(n.to_string()),
+            _ => None,
+        }
+    }
+    fn expected() -> &'static str {
+        "a string"
+    }
+}
+
+impl FromValue for u64 {
+    fn from_value(value: &Value) -> Option<u64> {
+        match *value {
+            Value::U64(n) => Some(n),
+            Value::I64(n) if n >= 0 => Some(n as u64),
+            Value::F64(n) if n >= 0.0 && n.fract() == 0.0 => Some(n as u64),
+            Value::String(ref s) => s.trim().parse().ok(),
+            _ => None,
+        }
+    }
+    fn expected() -> &'static str {
+        "a non-negative integer"
+    }
+}
+
+impl FromValue for i64 {
+    fn from_value(value: &Value) -> Option<i64> {
+        match *value {
+            Value::I64(n) => Some(n),
+            Value::U64(n) => Some(n as i64),
+            Value::F64(n) if n.fract() == 0.0 => Some(n as i64),
+            Value::String(ref s) => s.trim().parse().ok(),
+            _ => None,
+        }
+    }
+    fn expected() -> &'static str {
+        "an integer"
+    }
+}
+
+impl FromValue for f64 {
+    fn from_value(value: &Value) -> Option<f64> {
+        match *value {
+            Value::F64(n) => Some(n),
+            Value::I64(n) => Some(n as f64),
+            Value::U64(n) => Some(n as f64),
+            Value::String(ref s) => s.trim().parse().ok(),
+            _ => None,
+        }
+    }
+    fn expected() -> &'static str {
+        "a number"
+    }
+}
+
+impl FromValue for bool {
+    fn from_value(value: &Value) -> Option<bool> {
+        match *value {
+            Value::Bool(b) => Some(b),
+            Value::I64(n) => Some(n != 0),
+            Value::U64(n) => Some(n != 0),
+            _ => None,
+        }
+    }
+    fn expected() -> &'static str {
+        "a boolean"
+    }
+}
+
+impl FromValue for Value {
+    fn from_value(value: &Value) -> Option<Value> {
+        Some(value.clone())
+    }
+    fn expected() -> &'static str {
+        "any value"
+    }
+}
+
+/// Nulls and blank strings decode to `None`.
+impl<T: FromValue> FromValue for Option<T> {
+    fn from_value(value: &Value) -> Option<Option<T>> {
+        match *value {
+            Value::Null => Some(None),
+            Value::String(ref s) if s.trim().is_empty() => Some(None),
+            ref value => T::from_value(value).map(Some),
+        }
+    }
+    fn expected() -> &'static str {
+        T::expected()
+    }
+}
+
+
+/// Column positions of a result set, keyed by header name.
+#[derive(Debug, Clone)]
+pub struct Columns {
+    names: Vec<String>,
+    index: HashMap<String, usize>,
+}
+
+impl Columns {
+    pub fn new(headers: &Vec<Value>) -> Columns {
+        let names: Vec<String> = headers.iter()
+                                        .map(|x| x.as_string().unwrap_or("").to_owned())
+                                        .collect();
+        let index = names.iter().enumerate().map(|(i, x)| (x.clone(), i)).collect();
+        Columns {
+            names: names,
+            index: index,
+        }
+    }
+
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    pub fn position(&self, column: &str) -> Option<usize> {
+        self.index.get(column).cloned()
+    }
+}
+
+
+/// A single row of a result set, readable by column name.
+pub struct Row<'a> {
+    columns: &'a Columns,
+    values: &'a Vec<Value>,
+}
+
+impl<'a> Row<'a> {
+    pub fn new(columns: &'a Columns, values: &'a Vec<Value>) -> Row<'a> {
+        Row {
+            columns: columns,
+            values: values,
+        }
+    }
+
+    pub fn columns(&self) -> &'a Columns {
+        self.columns
+    }
+
+    /// The row's values, in column order.
+    pub fn values(&self) -> &'a Vec<Value> {
+        self.values
+    }
+
+    pub fn has(&self, column: &str) -> bool {
+        self.columns.position(column).is_some()
+    }
+
+    pub fn value(&self, column: &'static str) -> Result<&'a Value, NBAError> {
+        let idx = try!(self.columns.position(column).ok_or(NBAError::HeaderMissingError(column)));
+        self.values.get(idx).ok_or(NBAError::ArrayError)
+    }
+
+    pub fn get<T: FromValue>(&self, column: &'static str) -> Result<T, NBAError> {
+        let value = try!(self.value(column));
+        T::from_value(value).ok_or(NBAError::ColumnTypeError {
+            column: column,
+            value: value.to_string(),
+            expected: T::expected(),
+        })
+    }
+}
+
+
+/// Builds a value from one row of a result set.
+pub trait FromRow: Sized {
+    fn from_row(row: &Row) -> Result<Self, NBAError>;
+    /// Runs once over every decoded row of a result set, for values that depend on the rows
+    /// around them.
+    fn finish(_rows: &mut [Self]) {}
+}
+
+/// One named table of a stats.nba.com response.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResultSet {
+    pub name: String,
+    pub headers: Vec<Value>,
+    pub rows: Vec<Value>,
+}
+
+impl ResultSet {
+    pub fn from_value(value: &Value) -> Result<ResultSet, NBAError> {
+        let data = try!(value.as_object().ok_or(NBAError::ObjectError));
+        let name = data.get("name").and_then(|x| x.as_string()).unwrap_or("");
+        let headers = try!(data.get("headers").ok_or(NBAError::MissingField("headers")));
+        let headers = try!(headers.as_array().ok_or(NBAError::ArrayError));
+        let rows = try!(data.get("rowSet").ok_or(NBAError::MissingField("rowSet")));
+        let rows = try!(rows.as_array().ok_or(NBAError::ArrayError));
+        Ok(ResultSet {
+            name: name.to_owned(),
+            headers: headers.clone(),
+            rows: rows.clone(),
+        })
+    }
+
+    pub fn decode<T: FromRow>(&self) -> Result<Vec<T>, NBAError> {
+        decode_rows(&self.headers, &self.rows)
+    }
+}
+
+/// Reads every result set of a response.
+pub fn result_sets(data: &Value) -> Result<Vec<ResultSet>, NBAError> {
+    let data = try!(data.as_object().ok_or(NBAError::ObjectError));
+    // A few endpoints, like leagueleaders, return a lone `resultSet` instead of a list.
+    if let Some(set) = data.get("resultSet") {
+        return Ok(vec![try!(ResultSet::from_value(set))]);
+    }
+    let sets = try!(data.get("resultSets").ok_or(NBAError::MissingField("resultSets")));
+    let sets = try!(sets.as_array().ok_or(NBAError::ArrayError));
+    let mut parsed = Vec::new();
+    for set in sets {
+        parsed.push(try!(ResultSet::from_value(set)));
+    }
+    Ok(parsed)
+}
+
+/// Looks a result set up by name.
+pub fn find_result_set<'a>(sets: &'a [ResultSet],
+                           name: &'static str)
+                           -> Result<&'a ResultSet, NBAError> {
+    sets.iter().find(|x| x.name == name).ok_or(NBAError::MissingField(name))
+}
+
+/// Decodes every row of a `headers`/`rowSet` pair.
+pub fn decode_rows<T: FromRow>(headers: &Vec<Value>,
+                               rows: &Vec<Value>)
+                               -> Result<Vec<T>, NBAError> {
+    let columns = Columns::new(headers);
+    let mut decoded = Vec::new();
+    for row in rows {
+        let values = try!(row.as_array().ok_or(NBAError::ArrayError));
+        decoded.push(try!(T::from_row(&Row::new(&columns, values))));
+    }
+    T::finish(&mut decoded);
+    Ok(decoded)
+}
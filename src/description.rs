@@ -0,0 +1,156 @@
+use regex::Regex;
+
+
+lazy_static! {
+    static ref DISTANCE: Regex = Regex::new(r"(\d+)'").unwrap();
+    static ref POINTS: Regex = Regex::new(r"\((\d+) PTS\)").unwrap();
+    static ref ASSIST: Regex = Regex::new(r"\(([^()]+?) \d+ AST\)").unwrap();
+    static ref BLOCK: Regex = Regex::new(r"^(.+?) BLOCK \(\d+ BLK\)").unwrap();
+    static ref STEAL: Regex = Regex::new(r"^(.+?) STEAL \(\d+ STL\)").unwrap();
+    static ref COUNTS: Regex = Regex::new(r"\(P(\d+)(\.T(\d+)|\.PN)?\)").unwrap();
+    static ref JUMP_BALL: Regex = Regex::new(r"^Jump Ball (.+?) vs\. ").unwrap();
+    static ref SUBSTITUTION: Regex = Regex::new(r"^SUB: .+? FOR (.+)$").unwrap();
+}
+
+/// Title-case words that start the action part of a description rather than a name.
+static ACTION_WORDS: &'static [&'static str] = &["Jump", "Shot", "Layup", "Dunk", "Hook", "Tip",
+                                                 "Free", "Throw", "Bad", "Pass", "Lost",
+                                                 "Traveling", "Turnover", "Offensive", "Foul",
+                                                 "Driving", "Running", "Pullup", "Step",
+                                                 "Turnaround", "Fadeaway", "Floating", "Reverse",
+                                                 "Alley", "Putback", "Finger", "Cutting", "Bank",
+                                                 "Rebound", "Violation", "Slam", "Technical",
+                                                 "Goaltending", "Kicked", "Out", "Double",
+                                                 "Palming", "Discontinued", "Backcourt", "Lane",
+                                                 "Delay", "Inbound", "Illegal", "Personal",
+                                                 "Defense", "Defensive", "No"];
+
+
+/// What can be read out of a play-by-play description, e.g.
+/// "Korver 25' 3PT Jump Shot (3 PTS) (Teague 1 AST)".
+///
+/// Names are as written in the description, usually last names. The `PLAYERn` columns on
+/// the event carry the matching ids.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Description {
+    /// Whoever the description is about: the shooter, rebounder, fouler, or a team name
+    /// for team events.
+    pub player: Option<String>,
+    pub missed: bool,
+    pub three_point: bool,
+    pub distance: Option<u64>,
+    /// The shooter's running point total.
+    pub points: Option<u64>,
+    pub assist: Option<String>,
+    pub block: Option<String>,
+    pub steal: Option<String>,
+    /// The fouler's running foul count.
+    pub personal_fouls: Option<u64>,
+    /// The team's fouls in the period. `None` once the team is in the penalty.
+    pub team_fouls: Option<u64>,
+    pub penalty: bool,
+    /// The player's running turnover count.
+    pub turnovers: Option<u64>,
+    pub team_turnovers: Option<u64>,
+}
+
+fn capture(re: &Regex, text: &str) -> Option<String> {
+    re.captures(text).and_then(|x| x.at(1)).map(|x| x.trim().to_owned())
+}
+
+fn capture_u64(re: &Regex, text: &str) -> Option<u64> {
+    re.captures(text).and_then(|x| x.at(1)).and_then(|x| x.parse().ok())
+}
+
+fn ends_name(token: &str) -> bool {
+    let inner_dot = token.trim_right_matches('.').contains('.');
+    let shouting = token.len() > 1 && token.chars().all(|c| c.is_uppercase());
+    token.starts_with('(') || token.ends_with(':') || DISTANCE.is_match(token) ||
+    token == "3PT" || inner_dot || shouting || ACTION_WORDS.contains(&token)
+}
+
+/// Reads the leading name off a description, stopping at the first word of the action.
+fn leading_name(text: &str) -> Option<String> {
+    let name: Vec<&str> = text.split_whitespace().take_while(|x| !ends_name(x)).collect();
+    if name.is_empty() {
+        None
+    } else {
+        Some(name.join(" "))
+    }
+}
+
+/// Parses a single description.
+pub fn parse(text: &str) -> Description {
+    let mut description = Description::default();
+    let text = text.trim();
+    let rest = if text.starts_with("MISS ") {
+        description.missed = true;
+        &text[5..]
+    } else {
+        text
+    };
+
+    description.block = capture(&BLOCK, rest);
+    description.steal = capture(&STEAL, rest);
+    if description.block.is_none() && description.steal.is_none() {
+        description.player = if JUMP_BALL.is_match(rest) {
+            capture(&JUMP_BALL, rest)
+        } else if SUBSTITUTION.is_match(rest) {
+            // The player going out is the event's first player.
+            capture(&SUBSTITUTION, rest)
+        } else {
+            leading_name(rest)
+        };
+    }
+
+    description.three_point = rest.split_whitespace().any(|x| x == "3PT");
+    description.distance = capture_u64(&DISTANCE, rest);
+    description.points = capture_u64(&POINTS, rest);
+    description.assist = capture(&ASSIST, rest);
+
+    // "(P2.T3)" counts turnovers on a turnover and fouls on a foul.
+    if let Some(caps) = COUNTS.captures(rest) {
+        let personal = caps.at(1).and_then(|x| x.parse().ok());
+        let team = caps.at(3).and_then(|x| x.parse().ok());
+        if rest.contains("Turnover") {
+            description.turnovers = personal;
+            description.team_turnovers = team;
+        } else {
+            description.personal_fouls = personal;
+            description.team_fouls = team;
+            description.penalty = caps.at(2) == Some(".PN");
+        }
+    }
+    description
+}
+
+impl Description {
+    /// Fills in whatever this description is missing from another one for the same event.
+    pub fn merge(mut self, other: Description) -> Description {
+        self.player = self.player.or(other.player);
+        self.missed = self.missed || other.missed;
+        self.three_point = self.three_point || other.three_point;
+        self.distance = self.distance.or(other.distance);
+        self.points = self.points.or(other.points);
+        self.assist = self.assist.or(other.assist);
+        self.block = self.block.or(other.block);
+        self.steal = self.steal.or(other.steal);
+        self.personal_fouls = self.personal_fouls.or(other.personal_fouls);
+        self.team_fouls = self.team_fouls.or(other.team_fouls);
+        self.penalty = self.penalty || other.penalty;
+        self.turnovers = self.turnovers.or(other.turnovers);
+        self.team_turnovers = self.team_turnovers.or(other.team_turnovers);
+        self
+    }
+}
+
+/// Parses and merges an event's home and visitor descriptions. A blocked shot, for one, is
+/// described on the shooter's side with the block on the other. Neutral descriptions only
+/// mark the start and end of periods, so there's nothing to read from them.
+pub fn parse_event(home: Option<&str>, visitor: Option<&str>) -> Description {
+    [home, visitor]
+        .iter()
+        .filter_map(|x| *x)
+        .map(parse)
+        .fold(Description::default(), |acc, x| acc.merge(x))
+}
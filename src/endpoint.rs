@@ -0,0 +1,325 @@
+use serde_json::Value;
+use stats::{Stat, PlayByPlayEvent, GameHeader, LineScore, StandingsRow, RosterEntry};
+use stats::{BoxScoreTraditionalPlayer, BoxScoreTraditionalTeam, BoxScoreAdvancedPlayer,
+            BoxScoreAdvancedTeam, BoxScoreFourFactorsPlayer, BoxScoreFourFactorsTeam};
+use stats::{Shot, ShotZoneAverage};
+use stats::{PlayerGameLogEntry, PlayerSeasonStats, PlayerCareerTotals, PlayerInfo};
+use stats::{TeamGameLogEntry, GameFinderResult};
+use stats::{LeagueLeader, LeagueDashPlayerRow, LeagueDashTeamRow};
+use scrape::Scrape;
+use decode::{FromRow, ResultSet, result_sets, find_result_set};
+use constants::*;
+use err::NBAError;
+use queries::Query;
+use transport::{Transport, HyperTransport};
+
+
+/// A stats.nba.com endpoint, and the row type its result set decodes into.
+pub trait Endpoint {
+    type Row: FromRow;
+    fn base_url() -> &'static str;
+    /// Name of the result set the rows are read from.
+    fn result_set() -> &'static str;
+}
+
+pub struct PlayByPlayEndpoint;
+pub struct GameHeaderEndpoint;
+/// Served from the same scoreboard response as `GameHeaderEndpoint`.
+pub struct LineScoreEndpoint;
+pub struct EastConfStandingsEndpoint;
+pub struct WestConfStandingsEndpoint;
+pub struct LeagueLeadersEndpoint;
+pub struct LeagueDashPlayerStatsEndpoint;
+pub struct LeagueDashTeamStatsEndpoint;
+pub struct TeamRosterEndpoint;
+pub struct BoxScoreTraditionalPlayersEndpoint;
+pub struct BoxScoreTraditionalTeamsEndpoint;
+pub struct BoxScoreAdvancedPlayersEndpoint;
+pub struct BoxScoreAdvancedTeamsEndpoint;
+pub struct BoxScoreFourFactorsPlayersEndpoint;
+pub struct BoxScoreFourFactorsTeamsEndpoint;
+pub struct ShotChartEndpoint;
+pub struct ShotChartLeagueAveragesEndpoint;
+pub struct PlayerGameLogEndpoint;
+pub struct PlayerSeasonTotalsEndpoint;
+pub struct PlayerCareerTotalsEndpoint;
+pub struct PlayerPostSeasonTotalsEndpoint;
+pub struct PlayerPostSeasonCareerTotalsEndpoint;
+pub struct CommonPlayerInfoEndpoint;
+pub struct TeamGameLogEndpoint;
+pub struct LeagueGameFinderEndpoint;
+
+impl Endpoint for PlayByPlayEndpoint {
+    type Row = PlayByPlayEvent;
+    fn base_url() -> &'static str {
+        PLAYBYPLAY_BASE_URL
+    }
+    fn result_set() -> &'static str {
+        "PlayByPlay"
+    }
+}
+
+impl Endpoint for GameHeaderEndpoint {
+    type Row = GameHeader;
+    fn base_url() -> &'static str {
+        GAMEHEADER_BASE_URL
+    }
+    fn result_set() -> &'static str {
+        "GameHeader"
+    }
+}
+
+impl Endpoint for LineScoreEndpoint {
+    type Row = LineScore;
+    fn base_url() -> &'static str {
+        GAMEHEADER_BASE_URL
+    }
+    fn result_set() -> &'static str {
+        "LineScore"
+    }
+}
+
+impl Endpoint for EastConfStandingsEndpoint {
+    type Row = StandingsRow;
+    fn base_url() -> &'static str {
+        EASTCONFSTANDINGS_BASE_URL
+    }
+    fn result_set() -> &'static str {
+        "EastConfStandingsByDay"
+    }
+}
+
+impl Endpoint for WestConfStandingsEndpoint {
+    type Row = StandingsRow;
+    fn base_url() -> &'static str {
+        WESTCONFSTANDINGS_BASE_URL
+    }
+    fn result_set() -> &'static str {
+        "WestConfStandingsByDay"
+    }
+}
+
+impl Endpoint for LeagueLeadersEndpoint {
+    type Row = LeagueLeader;
+    fn base_url() -> &'static str {
+        LEAGUELEADERS_BASE_URL
+    }
+    fn result_set() -> &'static str {
+        "LeagueLeaders"
+    }
+}
+
+impl Endpoint for LeagueDashPlayerStatsEndpoint {
+    type Row = LeagueDashPlayerRow;
+    fn base_url() -> &'static str {
+        LEAGUEDASHPLAYERSTATS_BASE_URL
+    }
+    fn result_set() -> &'static str {
+        "LeagueDashPlayerStats"
+    }
+}
+
+impl Endpoint for LeagueDashTeamStatsEndpoint {
+    type Row = LeagueDashTeamRow;
+    fn base_url() -> &'static str {
+        LEAGUEDASHTEAMSTATS_BASE_URL
+    }
+    fn result_set() -> &'static str {
+        "LeagueDashTeamStats"
+    }
+}
+
+impl Endpoint for TeamRosterEndpoint {
+    type Row = RosterEntry;
+    fn base_url() -> &'static str {
+        TEAMROSTER_BASE_URL
+    }
+    fn result_set() -> &'static str {
+        "CommonTeamRoster"
+    }
+}
+
+impl Endpoint for BoxScoreTraditionalPlayersEndpoint {
+    type Row = BoxScoreTraditionalPlayer;
+    fn base_url() -> &'static str {
+        BOXSCORETRADITIONAL_BASE_URL
+    }
+    fn result_set() -> &'static str {
+        "PlayerStats"
+    }
+}
+
+impl Endpoint for BoxScoreTraditionalTeamsEndpoint {
+    type Row = BoxScoreTraditionalTeam;
+    fn base_url() -> &'static str {
+        BOXSCORETRADITIONAL_BASE_URL
+    }
+    fn result_set() -> &'static str {
+        "TeamStats"
+    }
+}
+
+impl Endpoint for BoxScoreAdvancedPlayersEndpoint {
+    type Row = BoxScoreAdvancedPlayer;
+    fn base_url() -> &'static str {
+        BOXSCOREADVANCED_BASE_URL
+    }
+    fn result_set() -> &'static str {
+        "PlayerStats"
+    }
+}
+
+impl Endpoint for BoxScoreAdvancedTeamsEndpoint {
+    type Row = BoxScoreAdvancedTeam;
+    fn base_url() -> &'static str {
+        BOXSCOREADVANCED_BASE_URL
+    }
+    fn result_set() -> &'static str {
+        "TeamStats"
+    }
+}
+
+impl Endpoint for BoxScoreFourFactorsPlayersEndpoint {
+    type Row = BoxScoreFourFactorsPlayer;
+    fn base_url() -> &'static str {
+        BOXSCOREFOURFACTORS_BASE_URL
+    }
+    fn result_set() -> &'static str {
+        "sqlPlayersFourFactors"
+    }
+}
+
+impl Endpoint for BoxScoreFourFactorsTeamsEndpoint {
+    type Row = BoxScoreFourFactorsTeam;
+    fn base_url() -> &'static str {
+        BOXSCOREFOURFACTORS_BASE_URL
+    }
+    fn result_set() -> &'static str {
+        "sqlTeamsFourFactors"
+    }
+}
+
+impl Endpoint for ShotChartEndpoint {
+    type Row = Shot;
+    fn base_url() -> &'static str {
+        SHOTCHART_BASE_URL
+    }
+    fn result_set() -> &'static str {
+        "Shot_Chart_Detail"
+    }
+}
+
+impl Endpoint for ShotChartLeagueAveragesEndpoint {
+    type Row = ShotZoneAverage;
+    fn base_url() -> &'static str {
+        SHOTCHART_BASE_URL
+    }
+    fn result_set() -> &'static str {
+        "LeagueAverages"
+    }
+}
+
+impl Endpoint for PlayerGameLogEndpoint {
+    type Row = PlayerGameLogEntry;
+    fn base_url() -> &'static str {
+        PLAYERGAMELOG_BASE_URL
+    }
+    fn result_set() -> &'static str {
+        "PlayerGameLog"
+    }
+}
+
+impl Endpoint for PlayerSeasonTotalsEndpoint {
+    type Row = PlayerSeasonStats;
+    fn base_url() -> &'static str {
+        PLAYERCAREERSTATS_BASE_URL
+    }
+    fn result_set() -> &'static str {
+        "SeasonTotalsRegularSeason"
+    }
+}
+
+impl Endpoint for PlayerCareerTotalsEndpoint {
+    type Row = PlayerCareerTotals;
+    fn base_url() -> &'static str {
+        PLAYERCAREERSTATS_BASE_URL
+    }
+    fn result_set() -> &'static str {
+        "CareerTotalsRegularSeason"
+    }
+}
+
+impl Endpoint for PlayerPostSeasonTotalsEndpoint {
+    type Row = PlayerSeasonStats;
+    fn base_url() -> &'static str {
+        PLAYERCAREERSTATS_BASE_URL
+    }
+    fn result_set() -> &'static str {
+        "SeasonTotalsPostSeason"
+    }
+}
+
+impl Endpoint for PlayerPostSeasonCareerTotalsEndpoint {
+    type Row = PlayerCareerTotals;
+    fn base_url() -> &'static str {
+        PLAYERCAREERSTATS_BASE_URL
+    }
+    fn result_set() -> &'static str {
+        "CareerTotalsPostSeason"
+    }
+}
+
+impl Endpoint for CommonPlayerInfoEndpoint {
+    type Row = PlayerInfo;
+    fn base_url() -> &'static str {
+        COMMONPLAYERINFO_BASE_URL
+    }
+    fn result_set() -> &'static str {
+        "CommonPlayerInfo"
+    }
+}
+
+impl Endpoint for TeamGameLogEndpoint {
+    type Row = TeamGameLogEntry;
+    fn base_url() -> &'static str {
+        TEAMGAMELOG_BASE_URL
+    }
+    fn result_set() -> &'static str {
+        "TeamGameLog"
+    }
+}
+
+impl Endpoint for LeagueGameFinderEndpoint {
+    type Row = GameFinderResult;
+    fn base_url() -> &'static str {
+        LEAGUEGAMEFINDER_BASE_URL
+    }
+    fn result_set() -> &'static str {
+        "LeagueGameFinderResults"
+    }
+}
+
+
+/// Fetches `E`'s rows from stats.nba.com.
+pub fn fetch<E: Endpoint>(payload: Query) -> Result<Vec<E::Row>, NBAError> {
+    fetch_with::<E>(&HyperTransport::new(), payload)
+}
+
+/// Fetches `E`'s rows over the given transport.
+pub fn fetch_with<E: Endpoint>(transport: &Transport,
+                               payload: Query)
+                               -> Result<Vec<E::Row>, NBAError> {
+    let sets = try!(fetch_result_sets_with(transport, E::base_url(), payload));
+    let set = try!(find_result_set(&sets, E::result_set()));
+    set.decode()
+}
+
+/// Fetches every result set of a response over the given transport.
+pub fn fetch_result_sets_with(transport: &Transport,
+                              base_url: &str,
+                              payload: Query)
+                              -> Result<Vec<ResultSet>, NBAError> {
+    try!(Stat::check_payload(&payload));
+    let data: Value = try!(Stat::post_query_with(transport, base_url.to_owned(), payload));
+    result_sets(&data)
+}
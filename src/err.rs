@@ -1,6 +1,7 @@
 
 use std::error;
 use std::fmt;
+use std::io;
 use url::ParseError;
 use rustc_serialize::json::{EncoderError, DecoderError};
 use hyper::Error as hyperError;
@@ -16,7 +17,20 @@ pub enum NBAError {
     JSONDecoderError(DecoderError),
     HyperError(hyperError),
     JsonError(SerdeJsonError),
+    IoError(io::Error),
+    HttpStatusError(u16),
+    MissingResponse(String),
     MissingField(&'static str),
+    InvalidQuery {
+        field: &'static str,
+        value: String,
+        expected: &'static str,
+    },
+    ColumnTypeError {
+        column: &'static str,
+        value: String,
+        expected: &'static str,
+    },
     ArrayError,
     ObjectError,
     RegexError,
@@ -61,11 +75,41 @@ impl fmt::Display for NBAError {
                        "\x1b[1;31merror: \x1b[Json failed at {}. Try again.",
                        err)
             }
+            NBAError::IoError(ref err) => {
+                write!(f,
+                       "\x1b[1;31merror: \x1b[IO failed at {}. Try again.",
+                       err)
+            }
+            NBAError::HttpStatusError(ref status) => {
+                write!(f,
+                       "\x1b[1;31merror: \x1b[Server responded with status {}. Try again.",
+                       status)
+            }
+            NBAError::MissingResponse(ref url) => {
+                write!(f,
+                       "\x1b[1;31merror: \x1b[No response available for {}.",
+                       url)
+            }
             NBAError::MissingField(ref key) => {
                 write!(f,
                        "\x1b[1;31merror: \x1b[Key {} does not exist. Submit bug with query.",
                        key)
             }
+            NBAError::InvalidQuery { field, ref value, expected } => {
+                write!(f,
+                       "\x1b[1;31merror: \x1b[Invalid {} \"{}\" in query, expected {}.",
+                       field,
+                       value,
+                       expected)
+            }
+            NBAError::ColumnTypeError { column, ref value, expected } => {
+                write!(f,
+                       "\x1b[1;31merror: \x1b[Column {} holds {}, expected {}. Submit bug with \
+                        query.",
+                       column,
+                       value,
+                       expected)
+            }
             NBAError::ArrayError => write!(f, "\x1b[1;31merror: \x1b[ArrayError. Try again."),
             NBAError::ObjectError => write!(f, "\x1b[1;31merror: \x1b[ObjectError. Try again."),
             NBAError::RegexError => {
@@ -87,7 +131,12 @@ impl error::Error for NBAError {
             NBAError::JSONDecoderError(_) => "Decoder failed. Try again.",
             NBAError::HyperError(_) => "Hyper failed. Try again.",
             NBAError::JsonError(_) => "JSON failed. Try again.",
+            NBAError::IoError(_) => "IO failed. Try again.",
+            NBAError::HttpStatusError(_) => "Bad HTTP status. Try again.",
+            NBAError::MissingResponse(_) => "No response for url.",
             NBAError::MissingField(_) => "Missing field. Submit bug.",
+            NBAError::InvalidQuery { .. } => "Invalid query. Check payload.",
+            NBAError::ColumnTypeError { .. } => "Column has unexpected type. Submit bug.",
             NBAError::ObjectError => "Could not convert JSON to an object.",
             NBAError::ArrayError => "Could not convert JSON to an array.",
             NBAError::PostFailedError => "Post failed.",
@@ -104,7 +153,12 @@ impl error::Error for NBAError {
             NBAError::JSONDecoderError(_) => None,
             NBAError::HyperError(_) => None,
             NBAError::JsonError(_) => None,
+            NBAError::IoError(_) => None,
+            NBAError::HttpStatusError(_) => None,
+            NBAError::MissingResponse(_) => None,
             NBAError::MissingField(_) => None,
+            NBAError::InvalidQuery { .. } => None,
+            NBAError::ColumnTypeError { .. } => None,
             NBAError::ArrayError => None,
             NBAError::ObjectError => None,
             NBAError::PostFailedError => None,
@@ -145,3 +199,9 @@ impl From<SerdeJsonError> for NBAError {
         NBAError::JsonError(err)
     }
 }
+
+impl From<io::Error> for NBAError {
+    fn from(err: io::Error) -> NBAError {
+        NBAError::IoError(err)
+    }
+}
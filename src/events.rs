@@ -0,0 +1,277 @@
+use stats::{PlayByPlayEvent, EventPerson};
+
+
+/// The broad family of a field goal attempt, from `EVENTMSGACTIONTYPE`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ShotType {
+    JumpShot,
+    Layup,
+    Dunk,
+    HookShot,
+    TipShot,
+    Other(u64),
+}
+
+impl ShotType {
+    pub fn new(action_type: u64) -> ShotType {
+        match action_type {
+            1 | 2 | 45 | 46 | 47 | 63 | 66 | 78 | 79 | 80 | 81 | 82 | 83 | 84 | 85 | 86 | 101 |
+            102 | 103 | 104 | 105 => ShotType::JumpShot,
+            5 | 6 | 41 | 42 | 43 | 44 | 71 | 72 | 73 | 74 | 75 | 76 | 98 | 99 | 109 | 110 => {
+                ShotType::Layup
+            }
+            7 | 8 | 9 | 48 | 49 | 50 | 51 | 52 | 87 | 106 | 107 | 108 => ShotType::Dunk,
+            3 | 55 | 56 | 57 | 58 | 67 | 93 | 96 => ShotType::HookShot,
+            4 | 97 => ShotType::TipShot,
+            other => ShotType::Other(other),
+        }
+    }
+}
+
+
+/// Which free throw of a trip to the line, from `EVENTMSGACTIONTYPE`. Whether it went
+/// in is only in the description.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FreeThrowType {
+    Regular { number: u8, of: u8 },
+    Flagrant { number: u8, of: u8 },
+    ClearPath { number: u8, of: u8 },
+    Technical,
+    Other(u64),
+}
+
+impl FreeThrowType {
+    pub fn new(action_type: u64) -> FreeThrowType {
+        match action_type {
+            10 => FreeThrowType::Regular { number: 1, of: 1 },
+            11 => FreeThrowType::Regular { number: 1, of: 2 },
+            12 => FreeThrowType::Regular { number: 2, of: 2 },
+            13 => FreeThrowType::Regular { number: 1, of: 3 },
+            14 => FreeThrowType::Regular { number: 2, of: 3 },
+            15 => FreeThrowType::Regular { number: 3, of: 3 },
+            16 | 21 | 22 => FreeThrowType::Technical,
+            18 => FreeThrowType::Flagrant { number: 1, of: 2 },
+            19 => FreeThrowType::Flagrant { number: 2, of: 2 },
+            20 => FreeThrowType::Flagrant { number: 1, of: 1 },
+            25 => FreeThrowType::ClearPath { number: 1, of: 2 },
+            26 => FreeThrowType::ClearPath { number: 2, of: 2 },
+            27 => FreeThrowType::ClearPath { number: 1, of: 1 },
+            other => FreeThrowType::Other(other),
+        }
+    }
+
+    /// Whether this is the last free throw of the trip, after which play goes on.
+    pub fn is_last(&self) -> bool {
+        match *self {
+            FreeThrowType::Regular { number, of } |
+            FreeThrowType::Flagrant { number, of } |
+            FreeThrowType::ClearPath { number, of } => number == of,
+            FreeThrowType::Technical | FreeThrowType::Other(_) => false,
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TurnoverType {
+    BadPass,
+    LostBall,
+    Traveling,
+    OffensiveFoul,
+    ShotClock,
+    ThreeSeconds,
+    OutOfBounds,
+    Other(u64),
+}
+
+impl TurnoverType {
+    pub fn new(action_type: u64) -> TurnoverType {
+        match action_type {
+            1 | 45 => TurnoverType::BadPass,
+            2 => TurnoverType::LostBall,
+            4 => TurnoverType::Traveling,
+            8 => TurnoverType::ThreeSeconds,
+            11 => TurnoverType::ShotClock,
+            37 => TurnoverType::OffensiveFoul,
+            39 | 40 => TurnoverType::OutOfBounds,
+            other => TurnoverType::Other(other),
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FoulType {
+    Personal,
+    Shooting,
+    LooseBall,
+    Offensive,
+    Charge,
+    AwayFromPlay,
+    ClearPath,
+    Technical,
+    Flagrant1,
+    Flagrant2,
+    Other(u64),
+}
+
+impl FoulType {
+    pub fn new(action_type: u64) -> FoulType {
+        match action_type {
+            1 | 27 | 28 => FoulType::Personal,
+            2 | 29 => FoulType::Shooting,
+            3 => FoulType::LooseBall,
+            4 => FoulType::Offensive,
+            26 => FoulType::Charge,
+            6 => FoulType::AwayFromPlay,
+            9 => FoulType::ClearPath,
+            11 | 12 | 13 | 16 | 17 | 18 | 19 | 25 | 30 => FoulType::Technical,
+            14 => FoulType::Flagrant1,
+            15 => FoulType::Flagrant2,
+            other => FoulType::Other(other),
+        }
+    }
+
+    /// Fouls that count against a team's total in the penalty.
+    pub fn is_team_foul(&self) -> bool {
+        match *self {
+            FoulType::Personal | FoulType::Shooting | FoulType::LooseBall |
+            FoulType::AwayFromPlay | FoulType::ClearPath | FoulType::Flagrant1 |
+            FoulType::Flagrant2 => true,
+            _ => false,
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ViolationType {
+    DelayOfGame,
+    Goaltending,
+    Lane,
+    JumpBall,
+    KickedBall,
+    Other(u64),
+}
+
+impl ViolationType {
+    pub fn new(action_type: u64) -> ViolationType {
+        match action_type {
+            1 => ViolationType::DelayOfGame,
+            2 => ViolationType::Goaltending,
+            3 | 6 => ViolationType::Lane,
+            4 => ViolationType::JumpBall,
+            5 => ViolationType::KickedBall,
+            other => ViolationType::Other(other),
+        }
+    }
+}
+
+
+/// What happened in a play-by-play event, decoded from `EVENTMSGTYPE` and
+/// `EVENTMSGACTIONTYPE`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EventKind {
+    MadeShot(ShotType),
+    MissedShot(ShotType),
+    FreeThrow(FreeThrowType),
+    Rebound,
+    Turnover(TurnoverType),
+    Foul(FoulType),
+    Violation(ViolationType),
+    Substitution,
+    Timeout,
+    JumpBall,
+    Ejection,
+    PeriodStart,
+    PeriodEnd,
+    InstantReplay,
+    /// Message and action types we don't know about.
+    Unknown(u64, u64),
+}
+
+impl EventKind {
+    pub fn new(msg_type: u64, action_type: u64) -> EventKind {
+        match msg_type {
+            1 => EventKind::MadeShot(ShotType::new(action_type)),
+            2 => EventKind::MissedShot(ShotType::new(action_type)),
+            3 => EventKind::FreeThrow(FreeThrowType::new(action_type)),
+            4 => EventKind::Rebound,
+            5 => EventKind::Turnover(TurnoverType::new(action_type)),
+            6 => EventKind::Foul(FoulType::new(action_type)),
+            7 => EventKind::Violation(ViolationType::new(action_type)),
+            8 => EventKind::Substitution,
+            9 => EventKind::Timeout,
+            10 => EventKind::JumpBall,
+            11 => EventKind::Ejection,
+            12 => EventKind::PeriodStart,
+            13 => EventKind::PeriodEnd,
+            18 => EventKind::InstantReplay,
+            _ => EventKind::Unknown(msg_type, action_type),
+        }
+    }
+
+    /// Made or missed field goals. Free throws aren't field goals.
+    pub fn is_field_goal(&self) -> bool {
+        match *self {
+            EventKind::MadeShot(_) | EventKind::MissedShot(_) => true,
+            _ => false,
+        }
+    }
+}
+
+
+impl PlayByPlayEvent {
+    /// The event's kind, or `None` when `EVENTMSGTYPE` is missing.
+    pub fn kind(&self) -> Option<EventKind> {
+        self.eventmsgtype.map(|x| EventKind::new(x, self.eventmsgactiontype.unwrap_or(0)))
+    }
+
+    /// The player passing to a made shot.
+    pub fn assister(&self) -> Option<&EventPerson> {
+        match self.kind() {
+            Some(EventKind::MadeShot(_)) => self.player2.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// The player blocking a missed shot.
+    pub fn blocker(&self) -> Option<&EventPerson> {
+        match self.kind() {
+            Some(EventKind::MissedShot(_)) => self.player3.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// The player stealing the ball on a turnover.
+    pub fn stealer(&self) -> Option<&EventPerson> {
+        match self.kind() {
+            Some(EventKind::Turnover(_)) => self.player2.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// The player fouled.
+    pub fn fouled(&self) -> Option<&EventPerson> {
+        match self.kind() {
+            Some(EventKind::Foul(_)) => self.player2.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// The player coming in on a substitution.
+    pub fn sub_in(&self) -> Option<&EventPerson> {
+        match self.kind() {
+            Some(EventKind::Substitution) => self.player2.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// The player going out on a substitution.
+    pub fn sub_out(&self) -> Option<&EventPerson> {
+        match self.kind() {
+            Some(EventKind::Substitution) => self.player1.as_ref(),
+            _ => None,
+        }
+    }
+}
@@ -5,11 +5,32 @@ extern crate hyper;
 extern crate serde_json;
 extern crate url;
 extern crate regex;
+#[macro_use]
+extern crate lazy_static;
 
+#[macro_use]
+pub mod decode;
 pub mod stats;
 pub mod queries;
 pub mod err;
 pub mod scrape;
 pub mod parse;
 pub mod constants;
+pub mod transport;
+pub mod cassette;
+pub mod teams;
+pub mod params;
+pub mod builders;
+pub mod endpoint;
+pub mod shotchart;
+pub mod scoreboard;
+pub mod events;
+pub mod description;
+pub mod clock;
+pub mod replay;
+pub mod boxscore;
+pub mod lineups;
+pub mod possessions;
+pub mod winprob;
+pub mod momentum;
 pub mod tests;
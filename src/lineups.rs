@@ -0,0 +1,298 @@
+use std::collections::{BTreeMap, HashMap};
+use clock::GameClock;
+use possessions;
+use replay::{Replay, GameState, Side};
+use stats::{PlayByPlayEvent, Score};
+
+
+/// Time, points and possessions from one side's point of view.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Split {
+    pub seconds: u64,
+    pub points_for: u64,
+    pub points_against: u64,
+    pub possessions: u64,
+    pub opponent_possessions: u64,
+}
+
+impl Split {
+    pub fn plus_minus(&self) -> i64 {
+        self.points_for as i64 - self.points_against as i64
+    }
+
+    /// Points scored per 100 possessions.
+    pub fn offensive_rating(&self) -> Option<f64> {
+        per_100(self.points_for, self.possessions)
+    }
+
+    /// Points allowed per 100 opponent possessions.
+    pub fn defensive_rating(&self) -> Option<f64> {
+        per_100(self.points_against, self.opponent_possessions)
+    }
+
+    pub fn net_rating(&self) -> Option<f64> {
+        match (self.offensive_rating(), self.defensive_rating()) {
+            (Some(offense), Some(defense)) => Some(offense - defense),
+            _ => None,
+        }
+    }
+
+    fn add(&mut self, other: &Split) {
+        self.seconds += other.seconds;
+        self.points_for += other.points_for;
+        self.points_against += other.points_against;
+        self.possessions += other.possessions;
+        self.opponent_possessions += other.opponent_possessions;
+    }
+}
+
+fn per_100(points: u64, possessions: u64) -> Option<f64> {
+    if possessions == 0 {
+        None
+    } else {
+        Some(100.0 * points as f64 / possessions as f64)
+    }
+}
+
+
+/// A stretch of a period with the same ten players on court.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stint {
+    pub period: u64,
+    pub start: Option<GameClock>,
+    pub end: Option<GameClock>,
+    /// Player ids on court for each side, sorted.
+    pub home: Vec<String>,
+    pub visitor: Vec<String>,
+    pub seconds: u64,
+    pub home_points: u64,
+    pub visitor_points: u64,
+    pub home_possessions: u64,
+    pub visitor_possessions: u64,
+}
+
+impl Stint {
+    fn new(state: &GameState) -> Stint {
+        let (home, visitor) = lineups(state);
+        Stint {
+            period: state.period,
+            start: state.clock,
+            end: state.clock,
+            home: home,
+            visitor: visitor,
+            seconds: 0,
+            home_points: 0,
+            visitor_points: 0,
+            home_possessions: 0,
+            visitor_possessions: 0,
+        }
+    }
+
+    pub fn lineup(&self, side: Side) -> &[String] {
+        match side {
+            Side::Home => &self.home,
+            Side::Visitor => &self.visitor,
+        }
+    }
+
+    /// The stint from one side's point of view.
+    pub fn split(&self, side: Side) -> Split {
+        let (points_for, points_against, possessions, opponent_possessions) = match side {
+            Side::Home => {
+                (self.home_points,
+                 self.visitor_points,
+                 self.home_possessions,
+                 self.visitor_possessions)
+            }
+            Side::Visitor => {
+                (self.visitor_points,
+                 self.home_points,
+                 self.visitor_possessions,
+                 self.home_possessions)
+            }
+        };
+        Split {
+            seconds: self.seconds,
+            points_for: points_for,
+            points_against: points_against,
+            possessions: possessions,
+            opponent_possessions: opponent_possessions,
+        }
+    }
+
+    /// Nothing has happened yet, as when several substitutions come at one dead ball.
+    fn is_empty(&self) -> bool {
+        self.seconds == 0 && self.home_points == 0 && self.visitor_points == 0 &&
+        self.home_possessions == 0 && self.visitor_possessions == 0
+    }
+}
+
+fn lineups(state: &GameState) -> (Vec<String>, Vec<String>) {
+    let mut home = state.home.on_court.clone();
+    let mut visitor = state.visitor.on_court.clone();
+    home.sort();
+    visitor.sort();
+    (home, visitor)
+}
+
+/// Splits a game into lineup stints, using the lineups inferred by `Replay`. Points go to
+/// the lineup on court when they're scored, so free throws after a substitution count for
+/// the players who came in. Possessions, as split by `possessions::possessions` and leaving
+/// out heaves, go to the lineup on court when they end.
+pub fn stints(events: &[PlayByPlayEvent]) -> Vec<Stint> {
+    let mut ended: HashMap<u64, Vec<Side>> = HashMap::new();
+    for possession in possessions::possessions(events) {
+        if let (false, Some(eventnum)) = (possession.heave, possession.end_event) {
+            ended.entry(eventnum).or_insert_with(Vec::new).push(possession.side);
+        }
+    }
+
+    let mut stints = Vec::new();
+    let mut current: Option<Stint> = None;
+    let mut score = Score {
+        home: 0,
+        away: 0,
+    };
+    let mut last_elapsed = None;
+
+    for (event, state) in Replay::new(events) {
+        if let Some(ref mut stint) = current {
+            if let (Some(last), Some(now)) = (last_elapsed, event.elapsed()) {
+                if now > last {
+                    stint.seconds += now - last;
+                }
+            }
+            stint.home_points += state.score.home.saturating_sub(score.home);
+            stint.visitor_points += state.score.away.saturating_sub(score.away);
+            if let Some(sides) = event.eventnum.and_then(|x| ended.get(&x)) {
+                for side in sides {
+                    match *side {
+                        Side::Home => stint.home_possessions += 1,
+                        Side::Visitor => stint.visitor_possessions += 1,
+                    }
+                }
+            }
+            stint.end = state.clock;
+        }
+        if event.elapsed().is_some() {
+            last_elapsed = event.elapsed();
+        }
+
+        let (home, visitor) = lineups(&state);
+        let changed = match current {
+            Some(ref stint) => {
+                stint.period != state.period || stint.home != home || stint.visitor != visitor
+            }
+            None => true,
+        };
+        if changed {
+            let reuse = match current {
+                Some(ref stint) => stint.is_empty() && stint.period == state.period,
+                None => false,
+            };
+            if reuse {
+                if let Some(ref mut stint) = current {
+                    stint.home = home;
+                    stint.visitor = visitor;
+                }
+            } else {
+                if let Some(stint) = current.take() {
+                    stints.push(stint);
+                }
+                current = Some(Stint::new(&state));
+            }
+        }
+        score = state.score;
+    }
+    if let Some(stint) = current {
+        stints.push(stint);
+    }
+    stints
+}
+
+
+/// A player's team with them on and off the court.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OnOff {
+    pub player_id: String,
+    pub side: Side,
+    pub on: Split,
+    pub off: Split,
+}
+
+impl OnOff {
+    /// Net rating on minus net rating off.
+    pub fn net_difference(&self) -> Option<f64> {
+        match (self.on.net_rating(), self.off.net_rating()) {
+            (Some(on), Some(off)) => Some(on - off),
+            _ => None,
+        }
+    }
+}
+
+/// On/off splits for every player who took part in a stint, sorted by player id.
+pub fn on_off(stints: &[Stint]) -> Vec<OnOff> {
+    let mut players: BTreeMap<String, Side> = BTreeMap::new();
+    for stint in stints {
+        for &side in &[Side::Home, Side::Visitor] {
+            for id in stint.lineup(side) {
+                players.insert(id.clone(), side);
+            }
+        }
+    }
+    players.into_iter()
+           .map(|(id, side)| {
+               let mut split = OnOff {
+                   player_id: id,
+                   side: side,
+                   on: Split::default(),
+                   off: Split::default(),
+               };
+               for stint in stints {
+                   if stint.lineup(side).contains(&split.player_id) {
+                       split.on.add(&stint.split(side));
+                   } else {
+                       split.off.add(&stint.split(side));
+                   }
+               }
+               split
+           })
+           .collect()
+}
+
+
+/// A five-man unit's totals over all its stints.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineupSplit {
+    pub side: Side,
+    /// Player ids, sorted.
+    pub players: Vec<String>,
+    pub stints: usize,
+    pub split: Split,
+}
+
+/// Totals for every lineup either side used, in the order they first took the court.
+pub fn lineup_splits(stints: &[Stint]) -> Vec<LineupSplit> {
+    let mut splits: Vec<LineupSplit> = Vec::new();
+    for stint in stints {
+        for &side in &[Side::Home, Side::Visitor] {
+            let lineup = stint.lineup(side);
+            let found = splits.iter().position(|x| x.side == side && x.players == lineup);
+            let idx = match found {
+                Some(idx) => idx,
+                None => {
+                    splits.push(LineupSplit {
+                        side: side,
+                        players: lineup.to_vec(),
+                        stints: 0,
+                        split: Split::default(),
+                    });
+                    splits.len() - 1
+                }
+            };
+            splits[idx].stints += 1;
+            splits[idx].split.add(&stint.split(side));
+        }
+    }
+    splits
+}
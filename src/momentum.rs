@@ -0,0 +1,212 @@
+use std::cmp;
+use clock::GameClock;
+use replay::Side;
+use stats::{PlayByPlayEvent, Score};
+
+
+/// Where in a game something happened.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mark {
+    pub eventnum: Option<u64>,
+    pub period: Option<u64>,
+    pub clock: Option<GameClock>,
+    pub score: Score,
+}
+
+impl Mark {
+    fn new(event: &PlayByPlayEvent, score: Score) -> Mark {
+        Mark {
+            eventnum: event.eventnum,
+            period: event.period,
+            clock: event.pctimestring,
+            score: score,
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MomentumEvent {
+    /// Unanswered points by one side, reported once the other side scores or the game
+    /// ends.
+    Run {
+        side: Side,
+        points: u64,
+        start: Mark,
+        end: Mark,
+    },
+    /// `side` took the lead from the other side, with or without a tie in between.
+    LeadChange { side: Side, at: Mark },
+    Tie { at: Mark },
+}
+
+
+/// The runs, lead changes and ties of a game, with the biggest leads.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Momentum {
+    /// In game order. Runs come at the event that ended them.
+    pub events: Vec<MomentumEvent>,
+    pub largest_home_lead: u64,
+    pub largest_visitor_lead: u64,
+    /// The last score.
+    pub score: Score,
+}
+
+impl Momentum {
+    pub fn lead_changes(&self) -> usize {
+        self.events
+            .iter()
+            .filter(|x| {
+                match **x {
+                    MomentumEvent::LeadChange { .. } => true,
+                    _ => false,
+                }
+            })
+            .count()
+    }
+
+    pub fn ties(&self) -> usize {
+        self.events
+            .iter()
+            .filter(|x| {
+                match **x {
+                    MomentumEvent::Tie { .. } => true,
+                    _ => false,
+                }
+            })
+            .count()
+    }
+
+    pub fn largest_lead(&self, side: Side) -> u64 {
+        match side {
+            Side::Home => self.largest_home_lead,
+            Side::Visitor => self.largest_visitor_lead,
+        }
+    }
+
+    /// The side leading at the end and the biggest deficit it came back from, if it ever
+    /// trailed.
+    pub fn comeback(&self) -> Option<(Side, u64)> {
+        let leader = match leader(self.score) {
+            Some(leader) => leader,
+            None => return None,
+        };
+        match self.largest_lead(leader.other()) {
+            0 => None,
+            deficit => Some((leader, deficit)),
+        }
+    }
+}
+
+fn leader(score: Score) -> Option<Side> {
+    if score.home > score.away {
+        Some(Side::Home)
+    } else if score.away > score.home {
+        Some(Side::Visitor)
+    } else {
+        None
+    }
+}
+
+/// Adds `points` by `side` to the current run, or starts a new one, recording the run it
+/// ends if that was long enough.
+fn extend_run(run: Option<(Side, u64, Mark, Mark)>,
+              side: Side,
+              points: u64,
+              mark: Mark,
+              min_run: u64,
+              found: &mut Vec<MomentumEvent>)
+              -> Option<(Side, u64, Mark, Mark)> {
+    match run {
+        Some((run_side, run_points, start, _)) if run_side == side => {
+            Some((side, run_points + points, start, mark))
+        }
+        previous => {
+            if let Some((run_side, run_points, start, end)) = previous {
+                if run_points >= min_run {
+                    found.push(MomentumEvent::Run {
+                        side: run_side,
+                        points: run_points,
+                        start: start,
+                        end: end,
+                    });
+                }
+            }
+            Some((side, points, mark, mark))
+        }
+    }
+}
+
+/// Finds runs of at least `min_run` unanswered points, lead changes and ties, from the
+/// running `score` of a game's events.
+pub fn momentum(events: &[PlayByPlayEvent], min_run: u64) -> Momentum {
+    let mut events: Vec<&PlayByPlayEvent> = events.iter().collect();
+    events.sort_by_key(|x| x.eventnum);
+
+    let mut found = Vec::new();
+    let mut largest_home_lead = 0;
+    let mut largest_visitor_lead = 0;
+    let mut score = Score {
+        home: 0,
+        away: 0,
+    };
+    let mut last_leader = None;
+    // The side on a run, its points, and where it started and last scored.
+    let mut run: Option<(Side, u64, Mark, Mark)> = None;
+
+    for event in events {
+        let new_score = match event.score {
+            Some(new_score) if new_score != score => new_score,
+            _ => continue,
+        };
+        // A score that goes down is a correction: take it on, but nobody scored.
+        let home_points = new_score.home.saturating_sub(score.home);
+        let visitor_points = new_score.away.saturating_sub(score.away);
+        score = new_score;
+        if home_points == 0 && visitor_points == 0 {
+            continue;
+        }
+        let mark = Mark::new(event, score);
+
+        for &(side, points) in &[(Side::Home, home_points), (Side::Visitor, visitor_points)] {
+            if points > 0 {
+                run = extend_run(run, side, points, mark, min_run, &mut found);
+            }
+        }
+
+        match leader(score) {
+            Some(side) => {
+                if last_leader.is_some() && last_leader != Some(side) {
+                    found.push(MomentumEvent::LeadChange {
+                        side: side,
+                        at: mark,
+                    });
+                }
+                last_leader = Some(side);
+            }
+            None => found.push(MomentumEvent::Tie { at: mark }),
+        }
+        if score.home > score.away {
+            largest_home_lead = cmp::max(largest_home_lead, score.home - score.away);
+        } else {
+            largest_visitor_lead = cmp::max(largest_visitor_lead, score.away - score.home);
+        }
+    }
+
+    if let Some((side, points, start, end)) = run {
+        if points >= min_run {
+            found.push(MomentumEvent::Run {
+                side: side,
+                points: points,
+                start: start,
+                end: end,
+            });
+        }
+    }
+    Momentum {
+        events: found,
+        largest_home_lead: largest_home_lead,
+        largest_visitor_lead: largest_visitor_lead,
+        score: score,
+    }
+}
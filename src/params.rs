@@ -0,0 +1,401 @@
+use std::fmt;
+use std::str::FromStr;
+use err::NBAError;
+
+
+fn invalid(field: &'static str, value: &str, expected: &'static str) -> NBAError {
+    NBAError::InvalidQuery {
+        field: field,
+        value: value.to_owned(),
+        expected: expected,
+    }
+}
+
+
+/// A ten digit stats.nba.com game id, e.g. "0041400106".
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct GameId(String);
+
+impl GameId {
+    pub fn new(id: &str) -> Result<GameId, NBAError> {
+        if id.len() == 10 && id.chars().all(|c| c.is_digit(10)) {
+            Ok(GameId(id.to_owned()))
+        } else {
+            Err(invalid("gameid", id, "a 10 digit game id"))
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for GameId {
+    type Err = NBAError;
+    fn from_str(s: &str) -> Result<GameId, NBAError> {
+        GameId::new(s)
+    }
+}
+
+impl fmt::Display for GameId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+
+/// A period filter between 0 and 14. Periods 1-4 are quarters, 5 and up are overtimes,
+/// and 0 means "from the start of the game".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Period(u8);
+
+impl Period {
+    pub fn new(period: u8) -> Result<Period, NBAError> {
+        if period <= 14 {
+            Ok(Period(period))
+        } else {
+            Err(invalid("period", &period.to_string(), "a period between 0 and 14"))
+        }
+    }
+
+    pub fn first() -> Period {
+        Period(0)
+    }
+
+    pub fn last() -> Period {
+        Period(14)
+    }
+
+    pub fn value(&self) -> u8 {
+        self.0
+    }
+}
+
+impl fmt::Display for Period {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+
+/// A season, named by the year it starts in. `Season::new(2015)` formats as "2015-16".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Season {
+    start_year: u16,
+}
+
+impl Season {
+    /// Both years have to fit in four digits.
+    pub fn new(start_year: u16) -> Result<Season, NBAError> {
+        if start_year >= 1000 && start_year < 9999 {
+            Ok(Season { start_year: start_year })
+        } else {
+            Err(invalid("season",
+                        &start_year.to_string(),
+                        "a start year between 1000 and 9998"))
+        }
+    }
+
+    pub fn start_year(&self) -> u16 {
+        self.start_year
+    }
+
+    pub fn end_year(&self) -> u16 {
+        self.start_year + 1
+    }
+}
+
+impl FromStr for Season {
+    type Err = NBAError;
+    /// Parses "2015-16" style seasons.
+    fn from_str(s: &str) -> Result<Season, NBAError> {
+        let err = || invalid("season", s, "a season formatted YYYY-YY");
+        let parts: Vec<&str> = s.split('-').collect();
+        if parts.len() != 2 || parts[0].len() != 4 || parts[1].len() != 2 {
+            return Err(err());
+        }
+        let start_year = try!(parts[0].parse::<u16>().map_err(|_| err()));
+        let end_year = try!(parts[1].parse::<u16>().map_err(|_| err()));
+        if (start_year + 1) % 100 != end_year {
+            return Err(err());
+        }
+        Season::new(start_year)
+    }
+}
+
+impl fmt::Display for Season {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-{:02}", self.start_year, self.end_year() % 100)
+    }
+}
+
+
+/// How many days after the scoreboard date to look, between 0 and 99.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DayOffset(u8);
+
+impl DayOffset {
+    pub fn new(days: u8) -> Result<DayOffset, NBAError> {
+        if days <= 99 {
+            Ok(DayOffset(days))
+        } else {
+            Err(invalid("dayoffset", &days.to_string(), "a one or two digit offset"))
+        }
+    }
+
+    pub fn zero() -> DayOffset {
+        DayOffset(0)
+    }
+
+    pub fn value(&self) -> u8 {
+        self.0
+    }
+}
+
+impl fmt::Display for DayOffset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+
+/// A calendar date, sent to stats.nba.com as "MM/DD/YYYY".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct GameDate {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl GameDate {
+    pub fn new(year: u16, month: u8, day: u8) -> Result<GameDate, NBAError> {
+        if month < 1 || month > 12 || day < 1 || day > days_in_month(year, month) {
+            let value = format!("{:02}/{:02}/{:04}", month, day, year);
+            return Err(invalid("gamedate", &value, "a valid calendar date"));
+        }
+        Ok(GameDate {
+            year: year,
+            month: month,
+            day: day,
+        })
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+}
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl FromStr for GameDate {
+    type Err = NBAError;
+    /// Parses "MM/DD/YYYY" dates.
+    fn from_str(s: &str) -> Result<GameDate, NBAError> {
+        let err = || invalid("gamedate", s, "a date formatted MM/DD/YYYY");
+        let parts: Vec<&str> = s.split('/').collect();
+        if parts.len() != 3 || parts[0].len() != 2 || parts[1].len() != 2 || parts[2].len() != 4 {
+            return Err(err());
+        }
+        let month = try!(parts[0].parse::<u8>().map_err(|_| err()));
+        let day = try!(parts[1].parse::<u8>().map_err(|_| err()));
+        let year = try!(parts[2].parse::<u16>().map_err(|_| err()));
+        GameDate::new(year, month, day)
+    }
+}
+
+impl fmt::Display for GameDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:02}/{:02}/{:04}", self.month, self.day, self.year)
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum League {
+    NBA,
+    WNBA,
+    DLeague,
+}
+
+impl League {
+    pub fn id(&self) -> &'static str {
+        match *self {
+            League::NBA => "00",
+            League::WNBA => "10",
+            League::DLeague => "20",
+        }
+    }
+}
+
+impl FromStr for League {
+    type Err = NBAError;
+    fn from_str(s: &str) -> Result<League, NBAError> {
+        match s {
+            "00" => Ok(League::NBA),
+            "10" => Ok(League::WNBA),
+            "20" => Ok(League::DLeague),
+            _ => Err(invalid("leagueid", s, "a league id of 00, 10 or 20")),
+        }
+    }
+}
+
+impl fmt::Display for League {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.id())
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SeasonType {
+    RegularSeason,
+    Playoffs,
+    PreSeason,
+    AllStar,
+}
+
+impl SeasonType {
+    pub fn name(&self) -> &'static str {
+        match *self {
+            SeasonType::RegularSeason => "Regular Season",
+            SeasonType::Playoffs => "Playoffs",
+            SeasonType::PreSeason => "Pre Season",
+            SeasonType::AllStar => "All Star",
+        }
+    }
+}
+
+impl FromStr for SeasonType {
+    type Err = NBAError;
+    fn from_str(s: &str) -> Result<SeasonType, NBAError> {
+        match s {
+            "Regular Season" => Ok(SeasonType::RegularSeason),
+            "Playoffs" => Ok(SeasonType::Playoffs),
+            "Pre Season" => Ok(SeasonType::PreSeason),
+            "All Star" => Ok(SeasonType::AllStar),
+            _ => {
+                Err(invalid("seasontype",
+                            s,
+                            "Regular Season, Playoffs, Pre Season or All Star"))
+            }
+        }
+    }
+}
+
+impl fmt::Display for SeasonType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+
+/// How counting stats are aggregated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PerMode {
+    Totals,
+    PerGame,
+    Per36,
+    Per100Possessions,
+}
+
+impl PerMode {
+    pub fn name(&self) -> &'static str {
+        match *self {
+            PerMode::Totals => "Totals",
+            PerMode::PerGame => "PerGame",
+            PerMode::Per36 => "Per36",
+            PerMode::Per100Possessions => "Per100Possessions",
+        }
+    }
+}
+
+impl FromStr for PerMode {
+    type Err = NBAError;
+    fn from_str(s: &str) -> Result<PerMode, NBAError> {
+        match s {
+            "Totals" => Ok(PerMode::Totals),
+            "PerGame" => Ok(PerMode::PerGame),
+            "Per36" => Ok(PerMode::Per36),
+            "Per100Possessions" => Ok(PerMode::Per100Possessions),
+            _ => Err(invalid("permode", s, "Totals, PerGame, Per36 or Per100Possessions")),
+        }
+    }
+}
+
+impl fmt::Display for PerMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+
+/// Which family of stats the league dashboards return.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MeasureType {
+    Base,
+    Advanced,
+    Misc,
+    FourFactors,
+    Scoring,
+    Opponent,
+    Usage,
+    Defense,
+}
+
+impl MeasureType {
+    pub fn name(&self) -> &'static str {
+        match *self {
+            MeasureType::Base => "Base",
+            MeasureType::Advanced => "Advanced",
+            MeasureType::Misc => "Misc",
+            MeasureType::FourFactors => "Four Factors",
+            MeasureType::Scoring => "Scoring",
+            MeasureType::Opponent => "Opponent",
+            MeasureType::Usage => "Usage",
+            MeasureType::Defense => "Defense",
+        }
+    }
+}
+
+impl FromStr for MeasureType {
+    type Err = NBAError;
+    fn from_str(s: &str) -> Result<MeasureType, NBAError> {
+        match s {
+            "Base" => Ok(MeasureType::Base),
+            "Advanced" => Ok(MeasureType::Advanced),
+            "Misc" => Ok(MeasureType::Misc),
+            "Four Factors" => Ok(MeasureType::FourFactors),
+            "Scoring" => Ok(MeasureType::Scoring),
+            "Opponent" => Ok(MeasureType::Opponent),
+            "Usage" => Ok(MeasureType::Usage),
+            "Defense" => Ok(MeasureType::Defense),
+            _ => {
+                Err(invalid("measuretype",
+                            s,
+                            "Base, Advanced, Misc, Four Factors, Scoring, Opponent, Usage or \
+                             Defense"))
+            }
+        }
+    }
+}
+
+impl fmt::Display for MeasureType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
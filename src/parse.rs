@@ -1,5 +1,15 @@
 use serde_json::Value;
-use stats::Stat;
+use stats::{PlayByPlayEvent, EventPerson, GameHeader, StandingsRow, RosterEntry};
+use stats::{BoxScoreTraditionalPlayer, BoxScoreTraditionalTeam, BoxScoreAdvancedPlayer,
+            BoxScoreAdvancedTeam, BoxScoreFourFactorsPlayer, BoxScoreFourFactorsTeam};
+use stats::{Shot, ShotZoneAverage};
+use stats::{PlayerGameLogEntry, PlayerSeasonStats, PlayerCareerTotals, PlayerInfo};
+use stats::{TeamGameLogEntry, GameFinderResult};
+use stats::{LeagueLeader, LeagueDashPlayerRow, LeagueDashTeamRow};
+use stats::{SeriesStanding, LastMeeting, Availability, LineScore};
+use std::collections::BTreeMap;
+use description;
+use decode::{decode_rows, FromRow, FromValue, Row};
 use err::NBAError;
 
 pub fn find_idx(ls: &Vec<Value>, elem: &'static str) -> Result<usize, NBAError> {
@@ -8,203 +18,686 @@ pub fn find_idx(ls: &Vec<Value>, elem: &'static str) -> Result<usize, NBAError>
       .ok_or(NBAError::HeaderMissingError(elem))
 }
 
-pub fn parse_playbyplay(headers: &Vec<Value>, rows: &Vec<Value>) -> Result<Vec<Stat>, NBAError> {
-    let mut raw_features = Vec::new();
-    let game_id_idx = try!(find_idx(headers, "GAME_ID"));
-    let eventnum_idx = try!(find_idx(headers, "EVENTNUM"));
-    let eventmsgtype_idx = try!(find_idx(headers, "EVENTMSGTYPE"));
-    let eventmsgactiontype_idx = try!(find_idx(headers, "EVENTMSGACTIONTYPE"));
-    let period_idx = try!(find_idx(headers, "PERIOD"));
-    let wctimestring_idx = try!(find_idx(headers, "WCTIMESTRING"));
-    let pctimestring_idx = try!(find_idx(headers, "PCTIMESTRING"));
-    let homedescription_idx = try!(find_idx(headers, "HOMEDESCRIPTION"));
-    let neutraldescription_idx = try!(find_idx(headers, "NEUTRALDESCRIPTION"));
-    let visitordescription_idx = try!(find_idx(headers, "VISITORDESCRIPTION"));
-    let score_idx = try!(find_idx(headers, "SCORE"));
-    let scoremargin_idx = try!(find_idx(headers, "SCOREMARGIN"));
-    for row in rows {
-        let row = row.as_array().unwrap();
-        let feat = Stat::PlayByPlay {
-            game_id: row[game_id_idx].as_i64(),
-            eventnum: row[eventnum_idx].as_u64(),
-            eventmsgtype: row[eventmsgtype_idx].as_u64(),
-            eventmsgactiontype: row[eventmsgactiontype_idx].as_u64(),
-            period: row[period_idx].as_u64(),
-            wctimestring: row[wctimestring_idx].as_string().map(|x| x.to_owned()),
-            pctimestring: row[pctimestring_idx].as_string().map(|x| x.to_owned()),
-            homedescription: row[homedescription_idx]
-                                 .as_string()
-                                 .map(|x| x.to_owned()),
-            neutraldescription: row[neutraldescription_idx]
-                                    .as_string()
-                                    .map(|x| x.to_owned()),
-            visitordescription: row[visitordescription_idx]
-                                    .as_string()
-                                    .map(|x| x.to_owned()),
-            score: row[score_idx].as_u64(),
-            scoremargin: row[scoremargin_idx].as_u64(),
-        };
-        raw_features.push(feat);
+/// Reads ids that stats.nba.com sends as either strings or numbers.
+#[deprecated(since = "0.2.0", note = "use `decode::FromValue` for `String`")]
+pub fn as_id(value: &Value) -> Option<String> {
+    String::from_value(value)
+}
+
+#[deprecated(since = "0.2.0", note = "use `decode::Row::get`")]
+pub fn cell(row: &Vec<Value>, idx: usize) -> Result<&Value, NBAError> {
+    row.get(idx).ok_or(NBAError::ArrayError)
+}
+
+/// Reads a column that not every response has.
+fn optional<T: FromValue>(row: &Row, column: &'static str) -> Result<Option<T>, NBAError> {
+    if row.has(column) {
+        row.get(column)
+    } else {
+        Ok(None)
+    }
+}
+
+/// Reads `PLAYERn_*`. Older responses have no person columns, and unused slots have an id
+/// of 0.
+fn event_person(row: &Row, n: usize) -> Result<Option<EventPerson>, NBAError> {
+    let (person_type, id, name, team_id, team_abbreviation) = match n {
+        1 => ("PERSON1TYPE", "PLAYER1_ID", "PLAYER1_NAME", "PLAYER1_TEAM_ID",
+              "PLAYER1_TEAM_ABBREVIATION"),
+        2 => ("PERSON2TYPE", "PLAYER2_ID", "PLAYER2_NAME", "PLAYER2_TEAM_ID",
+              "PLAYER2_TEAM_ABBREVIATION"),
+        _ => ("PERSON3TYPE", "PLAYER3_ID", "PLAYER3_NAME", "PLAYER3_TEAM_ID",
+              "PLAYER3_TEAM_ABBREVIATION"),
+    };
+    let id: Option<String> = try!(optional(row, id));
+    match id {
+        Some(ref id) if id != "0" => {
+            Ok(Some(EventPerson {
+                person_type: try!(optional(row, person_type)),
+                id: id.clone(),
+                name: try!(optional(row, name)),
+                team_id: try!(optional(row, team_id)),
+                team_abbreviation: try!(optional(row, team_abbreviation)),
+            }))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Reads `SCOREMARGIN`, which is a signed number or "TIE".
+fn score_margin(row: &Row) -> Result<Option<i64>, NBAError> {
+    let margin: Option<String> = try!(row.get("SCOREMARGIN"));
+    match margin {
+        Some(ref x) if x.trim() == "TIE" => Ok(Some(0)),
+        Some(x) => {
+            x.trim().parse().map(Some).map_err(|_| {
+                NBAError::ColumnTypeError {
+                    column: "SCOREMARGIN",
+                    value: x.clone(),
+                    expected: "a signed integer or \"TIE\"",
+                }
+            })
+        }
+        None => Ok(None),
+    }
+}
+
+impl FromRow for PlayByPlayEvent {
+    fn from_row(row: &Row) -> Result<PlayByPlayEvent, NBAError> {
+        let homedescription: Option<String> = try!(row.get("HOMEDESCRIPTION"));
+        let neutraldescription: Option<String> = try!(row.get("NEUTRALDESCRIPTION"));
+        let visitordescription: Option<String> = try!(row.get("VISITORDESCRIPTION"));
+        let details = description::parse_event(homedescription.as_ref().map(|x| &x[..]),
+                                               visitordescription.as_ref().map(|x| &x[..]));
+        Ok(PlayByPlayEvent {
+            game_id: try!(row.get("GAME_ID")),
+            eventnum: try!(row.get("EVENTNUM")),
+            eventmsgtype: try!(row.get("EVENTMSGTYPE")),
+            eventmsgactiontype: try!(row.get("EVENTMSGACTIONTYPE")),
+            period: try!(row.get("PERIOD")),
+            wctimestring: try!(row.get("WCTIMESTRING")),
+            pctimestring: try!(row.get("PCTIMESTRING")),
+            homedescription: homedescription,
+            neutraldescription: neutraldescription,
+            visitordescription: visitordescription,
+            score: try!(row.get("SCORE")),
+            scoremargin: try!(score_margin(row)),
+            player1: try!(event_person(row, 1)),
+            player2: try!(event_person(row, 2)),
+            player3: try!(event_person(row, 3)),
+            details: details,
+        })
+    }
+
+    /// Only scoring rows have a score, so carry the last one forward.
+    fn finish(events: &mut [PlayByPlayEvent]) {
+        let mut last = None;
+        for event in events {
+            if event.score.is_some() {
+                last = event.score;
+            } else {
+                event.score = last;
+            }
+            if event.scoremargin.is_none() {
+                event.scoremargin = last.map(|x| x.margin());
+            }
+        }
+    }
+}
+
+from_row!(GameHeader {
+    gamedate_est => "GAME_DATE_EST",
+    game_sequence => "GAME_SEQUENCE",
+    game_id => "GAME_ID",
+    game_status_id => "GAME_STATUS_ID",
+    game_status_text => "GAME_STATUS_TEXT",
+    gamecode => "GAMECODE",
+    home_team_id => "HOME_TEAM_ID",
+    visitor_team_id => "VISITOR_TEAM_ID",
+    season => "SEASON",
+    live_period => "LIVE_PERIOD",
+    live_pc_time => "LIVE_PC_TIME",
+    natl_tv_broadcaster_abbreviation => "NATL_TV_BROADCASTER_ABBREVIATION",
+    live_period_time_bcast => "LIVE_PERIOD_TIME_BCAST",
+    wh_status => "WH_STATUS",
+});
+
+from_row!(StandingsRow {
+    team_id => "TEAM_ID",
+    league_id => "LEAGUE_ID",
+    season_id => "SEASON_ID",
+    standings_date => "STANDINGSDATE",
+    conference => "CONFERENCE",
+    team => "TEAM",
+    g => "G",
+    wins => "W",
+    losses => "L",
+    w_pct => "W_PCT",
+    home_record => "HOME_RECORD",
+    road_record => "ROAD_RECORD",
+});
+
+from_row!(LineScore {
+    game_date_est => "GAME_DATE_EST",
+    game_sequence => "GAME_SEQUENCE",
+    game_id => "GAME_ID",
+    team_id => "TEAM_ID",
+    team_abbreviation => "TEAM_ABBREVIATION",
+    team_city_name => "TEAM_CITY_NAME",
+    team_wins_losses => "TEAM_WINS_LOSSES",
+    pts_qtr1 => "PTS_QTR1",
+    pts_qtr2 => "PTS_QTR2",
+    pts_qtr3 => "PTS_QTR3",
+    pts_qtr4 => "PTS_QTR4",
+    pts_ot1 => "PTS_OT1",
+    pts_ot2 => "PTS_OT2",
+    pts_ot3 => "PTS_OT3",
+    pts_ot4 => "PTS_OT4",
+    pts_ot5 => "PTS_OT5",
+    pts_ot6 => "PTS_OT6",
+    pts_ot7 => "PTS_OT7",
+    pts_ot8 => "PTS_OT8",
+    pts_ot9 => "PTS_OT9",
+    pts_ot10 => "PTS_OT10",
+    pts => "PTS",
+    fg_pct => "FG_PCT",
+    ft_pct => "FT_PCT",
+    fg3_pct => "FG3_PCT",
+    ast => "AST",
+    reb => "REB",
+    tov => "TOV",
+});
+
+from_row!(SeriesStanding {
+    game_id => "GAME_ID",
+    home_team_id => "HOME_TEAM_ID",
+    visitor_team_id => "VISITOR_TEAM_ID",
+    game_date_est => "GAME_DATE_EST",
+    home_team_wins => "HOME_TEAM_WINS",
+    home_team_losses => "HOME_TEAM_LOSSES",
+    series_leader => "SERIES_LEADER",
+});
+
+// stats.nba.com names the visitor's abbreviation column LAST_GAME_VISITOR_TEAM_CITY1.
+from_row!(LastMeeting {
+    game_id => "GAME_ID",
+    last_game_id => "LAST_GAME_ID",
+    last_game_date_est => "LAST_GAME_DATE_EST",
+    last_game_home_team_id => "LAST_GAME_HOME_TEAM_ID",
+    last_game_home_team_city => "LAST_GAME_HOME_TEAM_CITY",
+    last_game_home_team_name => "LAST_GAME_HOME_TEAM_NAME",
+    last_game_home_team_abbreviation => "LAST_GAME_HOME_TEAM_ABBREVIATION",
+    last_game_home_team_points => "LAST_GAME_HOME_TEAM_POINTS",
+    last_game_visitor_team_id => "LAST_GAME_VISITOR_TEAM_ID",
+    last_game_visitor_team_city => "LAST_GAME_VISITOR_TEAM_CITY",
+    last_game_visitor_team_name => "LAST_GAME_VISITOR_TEAM_NAME",
+    last_game_visitor_team_abbreviation => "LAST_GAME_VISITOR_TEAM_CITY1",
+    last_game_visitor_team_points => "LAST_GAME_VISITOR_TEAM_POINTS",
+});
+
+from_row!(Availability {
+    game_id => "GAME_ID",
+    pt_available => "PT_AVAILABLE",
+});
+
+from_row!(RosterEntry {
+    team_id => "TeamID",
+    season => "SEASON",
+    league_id => "LeagueID",
+    player => "PLAYER",
+    num => "NUM",
+    position => "POSITION",
+    height => "HEIGHT",
+    weight => "WEIGHT",
+    birth_date => "BIRTH_DATE",
+    age => "AGE",
+    exp => "EXP",
+    school => "SCHOOL",
+    player_id => "PLAYER_ID",
+});
+
+from_row!(BoxScoreTraditionalPlayer {
+    game_id => "GAME_ID",
+    team_id => "TEAM_ID",
+    team_abbreviation => "TEAM_ABBREVIATION",
+    team_city => "TEAM_CITY",
+    player_id => "PLAYER_ID",
+    player_name => "PLAYER_NAME",
+    start_position => "START_POSITION",
+    comment => "COMMENT",
+    min => "MIN",
+    fgm => "FGM",
+    fga => "FGA",
+    fg_pct => "FG_PCT",
+    fg3m => "FG3M",
+    fg3a => "FG3A",
+    fg3_pct => "FG3_PCT",
+    ftm => "FTM",
+    fta => "FTA",
+    ft_pct => "FT_PCT",
+    oreb => "OREB",
+    dreb => "DREB",
+    reb => "REB",
+    ast => "AST",
+    stl => "STL",
+    blk => "BLK",
+    tov => "TO",
+    pf => "PF",
+    pts => "PTS",
+    plus_minus => "PLUS_MINUS",
+});
+
+from_row!(BoxScoreTraditionalTeam {
+    game_id => "GAME_ID",
+    team_id => "TEAM_ID",
+    team_name => "TEAM_NAME",
+    team_abbreviation => "TEAM_ABBREVIATION",
+    team_city => "TEAM_CITY",
+    min => "MIN",
+    fgm => "FGM",
+    fga => "FGA",
+    fg_pct => "FG_PCT",
+    fg3m => "FG3M",
+    fg3a => "FG3A",
+    fg3_pct => "FG3_PCT",
+    ftm => "FTM",
+    fta => "FTA",
+    ft_pct => "FT_PCT",
+    oreb => "OREB",
+    dreb => "DREB",
+    reb => "REB",
+    ast => "AST",
+    stl => "STL",
+    blk => "BLK",
+    tov => "TO",
+    pf => "PF",
+    pts => "PTS",
+    plus_minus => "PLUS_MINUS",
+});
+
+from_row!(BoxScoreAdvancedPlayer {
+    game_id => "GAME_ID",
+    team_id => "TEAM_ID",
+    team_abbreviation => "TEAM_ABBREVIATION",
+    team_city => "TEAM_CITY",
+    player_id => "PLAYER_ID",
+    player_name => "PLAYER_NAME",
+    start_position => "START_POSITION",
+    comment => "COMMENT",
+    min => "MIN",
+    off_rating => "OFF_RATING",
+    def_rating => "DEF_RATING",
+    net_rating => "NET_RATING",
+    ast_pct => "AST_PCT",
+    ast_tov => "AST_TOV",
+    ast_ratio => "AST_RATIO",
+    oreb_pct => "OREB_PCT",
+    dreb_pct => "DREB_PCT",
+    reb_pct => "REB_PCT",
+    tm_tov_pct => "TM_TOV_PCT",
+    efg_pct => "EFG_PCT",
+    ts_pct => "TS_PCT",
+    usg_pct => "USG_PCT",
+    pace => "PACE",
+    pie => "PIE",
+});
+
+from_row!(BoxScoreAdvancedTeam {
+    game_id => "GAME_ID",
+    team_id => "TEAM_ID",
+    team_name => "TEAM_NAME",
+    team_abbreviation => "TEAM_ABBREVIATION",
+    team_city => "TEAM_CITY",
+    min => "MIN",
+    off_rating => "OFF_RATING",
+    def_rating => "DEF_RATING",
+    net_rating => "NET_RATING",
+    ast_pct => "AST_PCT",
+    ast_tov => "AST_TOV",
+    ast_ratio => "AST_RATIO",
+    oreb_pct => "OREB_PCT",
+    dreb_pct => "DREB_PCT",
+    reb_pct => "REB_PCT",
+    tm_tov_pct => "TM_TOV_PCT",
+    efg_pct => "EFG_PCT",
+    ts_pct => "TS_PCT",
+    usg_pct => "USG_PCT",
+    pace => "PACE",
+    pie => "PIE",
+});
+
+from_row!(BoxScoreFourFactorsPlayer {
+    game_id => "GAME_ID",
+    team_id => "TEAM_ID",
+    team_abbreviation => "TEAM_ABBREVIATION",
+    team_city => "TEAM_CITY",
+    player_id => "PLAYER_ID",
+    player_name => "PLAYER_NAME",
+    start_position => "START_POSITION",
+    comment => "COMMENT",
+    min => "MIN",
+    efg_pct => "EFG_PCT",
+    fta_rate => "FTA_RATE",
+    tm_tov_pct => "TM_TOV_PCT",
+    oreb_pct => "OREB_PCT",
+    opp_efg_pct => "OPP_EFG_PCT",
+    opp_fta_rate => "OPP_FTA_RATE",
+    opp_tov_pct => "OPP_TOV_PCT",
+    opp_oreb_pct => "OPP_OREB_PCT",
+});
+
+from_row!(BoxScoreFourFactorsTeam {
+    game_id => "GAME_ID",
+    team_id => "TEAM_ID",
+    team_name => "TEAM_NAME",
+    team_abbreviation => "TEAM_ABBREVIATION",
+    team_city => "TEAM_CITY",
+    min => "MIN",
+    efg_pct => "EFG_PCT",
+    fta_rate => "FTA_RATE",
+    tm_tov_pct => "TM_TOV_PCT",
+    oreb_pct => "OREB_PCT",
+    opp_efg_pct => "OPP_EFG_PCT",
+    opp_fta_rate => "OPP_FTA_RATE",
+    opp_tov_pct => "OPP_TOV_PCT",
+    opp_oreb_pct => "OPP_OREB_PCT",
+});
+
+from_row!(Shot {
+    game_id => "GAME_ID",
+    game_event_id => "GAME_EVENT_ID",
+    player_id => "PLAYER_ID",
+    player_name => "PLAYER_NAME",
+    team_id => "TEAM_ID",
+    team_name => "TEAM_NAME",
+    period => "PERIOD",
+    minutes_remaining => "MINUTES_REMAINING",
+    seconds_remaining => "SECONDS_REMAINING",
+    event_type => "EVENT_TYPE",
+    action_type => "ACTION_TYPE",
+    shot_type => "SHOT_TYPE",
+    shot_zone_basic => "SHOT_ZONE_BASIC",
+    shot_zone_area => "SHOT_ZONE_AREA",
+    shot_zone_range => "SHOT_ZONE_RANGE",
+    shot_distance => "SHOT_DISTANCE",
+    loc_x => "LOC_X",
+    loc_y => "LOC_Y",
+    shot_attempted_flag => "SHOT_ATTEMPTED_FLAG",
+    shot_made_flag => "SHOT_MADE_FLAG",
+});
+
+from_row!(ShotZoneAverage {
+    shot_zone_basic => "SHOT_ZONE_BASIC",
+    shot_zone_area => "SHOT_ZONE_AREA",
+    shot_zone_range => "SHOT_ZONE_RANGE",
+    fga => "FGA",
+    fgm => "FGM",
+    fg_pct => "FG_PCT",
+});
+
+from_row!(PlayerGameLogEntry {
+    season_id => "SEASON_ID",
+    player_id => "Player_ID",
+    game_id => "Game_ID",
+    game_date => "GAME_DATE",
+    matchup => "MATCHUP",
+    wl => "WL",
+    min => "MIN",
+    fgm => "FGM",
+    fga => "FGA",
+    fg_pct => "FG_PCT",
+    fg3m => "FG3M",
+    fg3a => "FG3A",
+    fg3_pct => "FG3_PCT",
+    ftm => "FTM",
+    fta => "FTA",
+    ft_pct => "FT_PCT",
+    oreb => "OREB",
+    dreb => "DREB",
+    reb => "REB",
+    ast => "AST",
+    stl => "STL",
+    blk => "BLK",
+    tov => "TOV",
+    pf => "PF",
+    pts => "PTS",
+    plus_minus => "PLUS_MINUS",
+    video_available => "VIDEO_AVAILABLE",
+});
+
+from_row!(PlayerSeasonStats {
+    player_id => "PLAYER_ID",
+    season_id => "SEASON_ID",
+    league_id => "LEAGUE_ID",
+    team_id => "TEAM_ID",
+    team_abbreviation => "TEAM_ABBREVIATION",
+    player_age => "PLAYER_AGE",
+    gp => "GP",
+    gs => "GS",
+    min => "MIN",
+    fgm => "FGM",
+    fga => "FGA",
+    fg_pct => "FG_PCT",
+    fg3m => "FG3M",
+    fg3a => "FG3A",
+    fg3_pct => "FG3_PCT",
+    ftm => "FTM",
+    fta => "FTA",
+    ft_pct => "FT_PCT",
+    oreb => "OREB",
+    dreb => "DREB",
+    reb => "REB",
+    ast => "AST",
+    stl => "STL",
+    blk => "BLK",
+    tov => "TOV",
+    pf => "PF",
+    pts => "PTS",
+});
+
+from_row!(PlayerCareerTotals {
+    player_id => "PLAYER_ID",
+    league_id => "LEAGUE_ID",
+    team_id => "Team_ID",
+    gp => "GP",
+    gs => "GS",
+    min => "MIN",
+    fgm => "FGM",
+    fga => "FGA",
+    fg_pct => "FG_PCT",
+    fg3m => "FG3M",
+    fg3a => "FG3A",
+    fg3_pct => "FG3_PCT",
+    ftm => "FTM",
+    fta => "FTA",
+    ft_pct => "FT_PCT",
+    oreb => "OREB",
+    dreb => "DREB",
+    reb => "REB",
+    ast => "AST",
+    stl => "STL",
+    blk => "BLK",
+    tov => "TOV",
+    pf => "PF",
+    pts => "PTS",
+});
+
+from_row!(PlayerInfo {
+    person_id => "PERSON_ID",
+    first_name => "FIRST_NAME",
+    last_name => "LAST_NAME",
+    display_first_last => "DISPLAY_FIRST_LAST",
+    birthdate => "BIRTHDATE",
+    school => "SCHOOL",
+    country => "COUNTRY",
+    height => "HEIGHT",
+    weight => "WEIGHT",
+    season_exp => "SEASON_EXP",
+    jersey => "JERSEY",
+    position => "POSITION",
+    rosterstatus => "ROSTERSTATUS",
+    team_id => "TEAM_ID",
+    team_name => "TEAM_NAME",
+    team_abbreviation => "TEAM_ABBREVIATION",
+    team_city => "TEAM_CITY",
+    from_year => "FROM_YEAR",
+    to_year => "TO_YEAR",
+    draft_year => "DRAFT_YEAR",
+    draft_round => "DRAFT_ROUND",
+    draft_number => "DRAFT_NUMBER",
+});
+
+from_row!(TeamGameLogEntry {
+    team_id => "Team_ID",
+    game_id => "Game_ID",
+    game_date => "GAME_DATE",
+    matchup => "MATCHUP",
+    wl => "WL",
+    wins => "W",
+    losses => "L",
+    w_pct => "W_PCT",
+    min => "MIN",
+    fgm => "FGM",
+    fga => "FGA",
+    fg_pct => "FG_PCT",
+    fg3m => "FG3M",
+    fg3a => "FG3A",
+    fg3_pct => "FG3_PCT",
+    ftm => "FTM",
+    fta => "FTA",
+    ft_pct => "FT_PCT",
+    oreb => "OREB",
+    dreb => "DREB",
+    reb => "REB",
+    ast => "AST",
+    stl => "STL",
+    blk => "BLK",
+    tov => "TOV",
+    pf => "PF",
+    pts => "PTS",
+});
+
+from_row!(GameFinderResult {
+    season_id => "SEASON_ID",
+    team_id => "TEAM_ID",
+    team_abbreviation => "TEAM_ABBREVIATION",
+    team_name => "TEAM_NAME",
+    game_id => "GAME_ID",
+    game_date => "GAME_DATE",
+    matchup => "MATCHUP",
+    wl => "WL",
+    min => "MIN",
+    pts => "PTS",
+    fgm => "FGM",
+    fga => "FGA",
+    fg_pct => "FG_PCT",
+    fg3m => "FG3M",
+    fg3a => "FG3A",
+    fg3_pct => "FG3_PCT",
+    ftm => "FTM",
+    fta => "FTA",
+    ft_pct => "FT_PCT",
+    oreb => "OREB",
+    dreb => "DREB",
+    reb => "REB",
+    ast => "AST",
+    stl => "STL",
+    blk => "BLK",
+    tov => "TOV",
+    pf => "PF",
+    plus_minus => "PLUS_MINUS",
+});
+
+from_row!(LeagueLeader {
+    player_id => "PLAYER_ID",
+    rank => "RANK",
+    player => "PLAYER",
+    team => "TEAM",
+    gp => "GP",
+    min => "MIN",
+    fgm => "FGM",
+    fga => "FGA",
+    fg_pct => "FG_PCT",
+    fg3m => "FG3M",
+    fg3a => "FG3A",
+    fg3_pct => "FG3_PCT",
+    ftm => "FTM",
+    fta => "FTA",
+    ft_pct => "FT_PCT",
+    oreb => "OREB",
+    dreb => "DREB",
+    reb => "REB",
+    ast => "AST",
+    stl => "STL",
+    blk => "BLK",
+    tov => "TOV",
+    pts => "PTS",
+    eff => "EFF",
+});
+
+/// Reads every numeric column of a dashboard row that isn't in `skip`.
+fn dash_stats(row: &Row, skip: &[&str]) -> BTreeMap<String, f64> {
+    row.columns()
+       .names()
+       .iter()
+       .zip(row.values().iter())
+       .filter(|&(name, _)| !skip.contains(&&name[..]))
+       .filter_map(|(name, value)| f64::from_value(value).map(|x| (name.clone(), x)))
+       .collect()
+}
+
+impl FromRow for LeagueDashPlayerRow {
+    fn from_row(row: &Row) -> Result<LeagueDashPlayerRow, NBAError> {
+        Ok(LeagueDashPlayerRow {
+            player_id: try!(row.get("PLAYER_ID")),
+            player_name: try!(row.get("PLAYER_NAME")),
+            team_id: try!(row.get("TEAM_ID")),
+            team_abbreviation: try!(row.get("TEAM_ABBREVIATION")),
+            age: try!(row.get("AGE")),
+            gp: try!(row.get("GP")),
+            wins: try!(row.get("W")),
+            losses: try!(row.get("L")),
+            w_pct: try!(row.get("W_PCT")),
+            min: try!(row.get("MIN")),
+            stats: dash_stats(row,
+                              &["PLAYER_ID", "PLAYER_NAME", "TEAM_ID", "TEAM_ABBREVIATION", "AGE",
+                                "GP", "W", "L", "W_PCT", "MIN", "CFID", "CFPARAMS"]),
+        })
     }
-    Ok(raw_features)
-}
-
-pub fn parse_gameheader(headers: &Vec<Value>, rows: &Vec<Value>) -> Result<Vec<Stat>, NBAError> {
-    let mut raw_features = Vec::new();
-    let gamedate_est_idx = try!(find_idx(headers, "GAMEDATE_EST"));
-    let game_sequence_idx = try!(find_idx(headers, "GAME_SEQUENCE"));
-    let game_id_idx = try!(find_idx(headers, "GAME_ID"));
-    let game_status_id_idx = try!(find_idx(headers, "GAME_STATUS_ID"));
-    let game_status_text_idx = try!(find_idx(headers, "GAME_STATUS_TEXT"));
-    let gamecode_idx = try!(find_idx(headers, "GAMECODE"));
-    let home_team_id_idx = try!(find_idx(headers, "HOME_TEAM_ID"));
-    let visitor_team_id_idx = try!(find_idx(headers, "VISITOR_TEAM_ID"));
-    let season_idx = try!(find_idx(headers, "SEASON"));
-    let live_period_idx = try!(find_idx(headers, "LIVE_PERIOD"));
-    let live_pc_time_idx = try!(find_idx(headers, "LIVE_PC_TIME"));
-    let natl_tv_broadcaster_abbreviation_idx = try!(find_idx(headers,
-                                                             "NATL_TV_BROADCASTER_ABBREVIATION"));
-    let live_period_time_bcast_idx = try!(find_idx(headers, "LIVE_PERIOD_TIME_BCAST"));
-    let wh_status_idx = try!(find_idx(headers, "WH_STATUS"));
-
-    for row in rows {
-        let row = row.as_array().unwrap();
-        let feat = Stat::GameHeader {
-            gamedate_est: row[gamedate_est_idx].as_string().map(|x| x.to_owned()),
-            game_sequence: row[game_sequence_idx].as_u64(),
-            game_id: row[game_id_idx].as_string().map(|x| x.to_owned()),
-            game_status_id: row[game_status_id_idx].as_u64(),
-            game_status_text: row[game_status_text_idx].as_string().map(|x| x.to_owned()),
-            gamecode: row[gamecode_idx].as_string().map(|x| x.to_owned()),
-            home_team_id: row[home_team_id_idx].as_string().map(|x| x.to_owned()),
-            visitor_team_id: row[visitor_team_id_idx].as_string().map(|x| x.to_owned()),
-            season: row[season_idx].as_u64(),
-            live_period: row[live_period_idx].as_u64(),
-            live_pc_time: row[live_pc_time_idx].as_u64(),
-            natl_tv_broadcaster_abbreviation: row[natl_tv_broadcaster_abbreviation_idx]
-                                                  .as_string()
-                                                  .map(|x| x.to_owned()),
-            live_period_time_bcast: row[live_period_time_bcast_idx]
-                                        .as_string()
-                                        .map(|x| x.to_owned()),
-            wh_status: row[wh_status_idx].as_u64(),
-        };
-        raw_features.push(feat);
+}
+
+impl FromRow for LeagueDashTeamRow {
+    fn from_row(row: &Row) -> Result<LeagueDashTeamRow, NBAError> {
+        Ok(LeagueDashTeamRow {
+            team_id: try!(row.get("TEAM_ID")),
+            team_name: try!(row.get("TEAM_NAME")),
+            gp: try!(row.get("GP")),
+            wins: try!(row.get("W")),
+            losses: try!(row.get("L")),
+            w_pct: try!(row.get("W_PCT")),
+            min: try!(row.get("MIN")),
+            stats: dash_stats(row,
+                              &["TEAM_ID", "TEAM_NAME", "GP", "W", "L", "W_PCT", "MIN", "CFID",
+                                "CFPARAMS"]),
+        })
     }
-    Ok(raw_features)
 }
 
+pub fn parse_playbyplay(headers: &Vec<Value>,
+                        rows: &Vec<Value>)
+                        -> Result<Vec<PlayByPlayEvent>, NBAError> {
+    decode_rows(headers, rows)
+}
 
+pub fn parse_gameheader(headers: &Vec<Value>,
+                        rows: &Vec<Value>)
+                        -> Result<Vec<GameHeader>, NBAError> {
+    decode_rows(headers, rows)
+}
+
+/// Parses either conference's `*ConfStandingsByDay` result set.
+pub fn parse_confstandings(headers: &Vec<Value>,
+                           rows: &Vec<Value>)
+                           -> Result<Vec<StandingsRow>, NBAError> {
+    decode_rows(headers, rows)
+}
+
+#[deprecated(since = "0.2.0", note = "use `parse_confstandings`")]
 pub fn parse_eastconfstandings(headers: &Vec<Value>,
                                rows: &Vec<Value>)
-                               -> Result<Vec<Stat>, NBAError> {
-    let mut raw_features = Vec::new();
-    let team_id_idx = try!(find_idx(headers, "TEAM_ID"));
-    let league_id_idx = try!(find_idx(headers, "LEAGUE_ID"));
-    let season_id_idx = try!(find_idx(headers, "SEASON_ID"));
-    let standings_date_idx = try!(find_idx(headers, "STANDINGSDATE"));
-    let conference_idx = try!(find_idx(headers, "CONFERENCE"));
-    let team_idx = try!(find_idx(headers, "TEAM"));
-    let g_idx = try!(find_idx(headers, "G"));
-    let wins_idx = try!(find_idx(headers, "W"));
-    let losses_idx = try!(find_idx(headers, "L"));
-    let w_pct_idx = try!(find_idx(headers, "W_PCT"));
-    let home_record_idx = try!(find_idx(headers, "HOME_RECORD"));
-    let road_record_idx = try!(find_idx(headers, "ROAD_RECORD"));
-
-    for row in rows {
-        let row = row.as_array().unwrap();
-        let feat = Stat::EastConfStandings {
-            team_id: row[team_id_idx].as_string().map(|x| x.to_owned()),
-            league_id: row[league_id_idx].as_string().map(|x| x.to_owned()),
-            season_id: row[season_id_idx].as_string().map(|x| x.to_owned()),
-            standings_date: row[standings_date_idx].as_string().map(|x| x.to_owned()),
-            conference: row[conference_idx].as_string().map(|x| x.to_owned()),
-            team: row[team_idx].as_string().map(|x| x.to_owned()),
-            g: row[g_idx].as_u64(),
-            wins: row[wins_idx].as_u64(),
-            losses: row[losses_idx].as_u64(),
-            w_pct: row[w_pct_idx].as_f64(),
-            home_record: row[home_record_idx].as_string().map(|x| x.to_owned()),
-            road_record: row[road_record_idx].as_string().map(|x| x.to_owned()),
-        };
-        raw_features.push(feat);
-    }
-    Ok(raw_features)
+                               -> Result<Vec<StandingsRow>, NBAError> {
+    parse_confstandings(headers, rows)
 }
 
+#[deprecated(since = "0.2.0", note = "use `parse_confstandings`")]
 pub fn parse_westconfstandings(headers: &Vec<Value>,
                                rows: &Vec<Value>)
-                               -> Result<Vec<Stat>, NBAError> {
-    let mut raw_features = Vec::new();
-    let team_id_idx = try!(find_idx(headers, "TEAM_ID"));
-    let league_id_idx = try!(find_idx(headers, "LEAGUE_ID"));
-    let season_id_idx = try!(find_idx(headers, "SEASON_ID"));
-    let standings_date_idx = try!(find_idx(headers, "STANDINGSDATE"));
-    let conference_idx = try!(find_idx(headers, "CONFERENCE"));
-    let team_idx = try!(find_idx(headers, "TEAM"));
-    let g_idx = try!(find_idx(headers, "G"));
-    let wins_idx = try!(find_idx(headers, "W"));
-    let losses_idx = try!(find_idx(headers, "L"));
-    let w_pct_idx = try!(find_idx(headers, "W_PCT"));
-    let home_record_idx = try!(find_idx(headers, "HOME_RECORD"));
-    let road_record_idx = try!(find_idx(headers, "ROAD_RECORD"));
-
-    for row in rows {
-        let row = row.as_array().unwrap();
-        let feat = Stat::WestConfStandings {
-            team_id: row[team_id_idx].as_string().map(|x| x.to_owned()),
-            league_id: row[league_id_idx].as_string().map(|x| x.to_owned()),
-            season_id: row[season_id_idx].as_string().map(|x| x.to_owned()),
-            standings_date: row[standings_date_idx].as_string().map(|x| x.to_owned()),
-            conference: row[conference_idx].as_string().map(|x| x.to_owned()),
-            team: row[team_idx].as_string().map(|x| x.to_owned()),
-            g: row[g_idx].as_u64(),
-            wins: row[wins_idx].as_u64(),
-            losses: row[losses_idx].as_u64(),
-            w_pct: row[w_pct_idx].as_f64(),
-            home_record: row[home_record_idx].as_string().map(|x| x.to_owned()),
-            road_record: row[road_record_idx].as_string().map(|x| x.to_owned()),
-        };
-        raw_features.push(feat);
-    }
-    Ok(raw_features)
-}
-
-
-pub fn parse_teamroster(headers: &Vec<Value>, rows: &Vec<Value>) -> Result<Vec<Stat>, NBAError> {
-    let mut raw_features = Vec::new();
-    let team_id_idx = try!(find_idx(headers, "TeamID"));
-    let season_idx = try!(find_idx(headers, "SEASON"));
-    let league_id_idx = try!(find_idx(headers, "LeagueID"));
-    let player_idx = try!(find_idx(headers, "PLAYER"));
-    let num_idx = try!(find_idx(headers, "NUM"));
-    let position_idx = try!(find_idx(headers, "POSITION"));
-    let height_idx = try!(find_idx(headers, "HEIGHT"));
-    let weight_idx = try!(find_idx(headers, "WEIGHT"));
-    let birth_date_idx = try!(find_idx(headers, "BIRTH_DATE"));
-    let age_idx = try!(find_idx(headers, "AGE"));
-    let exp_idx = try!(find_idx(headers, "EXP"));
-    let school_idx = try!(find_idx(headers, "SCHOOL"));
-    let player_id_idx = try!(find_idx(headers, "PLAYER_ID"));
-    for row in rows {
-        let row = row.as_array().unwrap();
-        let feat = Stat::TeamRoster {
-            team_id: row[team_id_idx].as_string().map(|x| x.to_owned()),
-            season: row[season_idx].as_string().map(|x| x.to_owned()),
-            league_id: row[league_id_idx].as_string().map(|x| x.to_owned()),
-            player: row[player_idx].as_string().map(|x| x.to_owned()),
-            num: row[num_idx].as_string().map(|x| x.to_owned()),
-            position: row[position_idx].as_string().map(|x| x.to_owned()),
-            height: row[height_idx].as_string().map(|x| x.to_owned()),
-            weight: row[weight_idx].as_string().map(|x| x.to_owned()),
-            birth_date: row[birth_date_idx].as_string().map(|x| x.to_owned()),
-            age: row[age_idx].as_u64(),
-            exp: row[exp_idx].as_string().map(|x| x.to_owned()),
-            school: row[school_idx].as_string().map(|x| x.to_owned()),
-            player_id: row[player_id_idx].as_string().map(|x| x.to_owned()),
-        };
-        raw_features.push(feat);
-    }
-    Ok(raw_features)
+                               -> Result<Vec<StandingsRow>, NBAError> {
+    parse_confstandings(headers, rows)
+}
+
+pub fn parse_teamroster(headers: &Vec<Value>,
+                        rows: &Vec<Value>)
+                        -> Result<Vec<RosterEntry>, NBAError> {
+    decode_rows(headers, rows)
 }
@@ -0,0 +1,185 @@
+use clock::{GameClock, period_seconds};
+use events::{EventKind, FreeThrowType};
+use replay::Side;
+use stats::{PlayByPlayEvent, Score};
+
+
+/// Possessions starting with this many seconds or less left in a period, and coming to
+/// nothing, are end-of-period heaves rather than real trips down the floor.
+pub static HEAVE_SECONDS: u64 = 2;
+
+
+/// One team's trip down the floor, from gaining the ball until the other team has it or
+/// the period ends. Offensive rebounds and and-one free throws don't start a new one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Possession {
+    pub side: Side,
+    pub period: u64,
+    pub start: Option<GameClock>,
+    pub end: Option<GameClock>,
+    /// `EVENTNUM` of the event `end` was taken from.
+    pub end_event: Option<u64>,
+    /// Points the offense scored, technical free throws aside.
+    pub points: u64,
+    /// Whether this is an end-of-period heave, left out of possession counts.
+    pub heave: bool,
+}
+
+/// The team whose possession an event shows, if it shows one. Fouls, timeouts and
+/// technical free throws can happen whoever has the ball.
+fn holder(event: &PlayByPlayEvent) -> Option<Side> {
+    let person = match event.kind() {
+        Some(EventKind::MadeShot(_)) |
+        Some(EventKind::MissedShot(_)) |
+        Some(EventKind::Turnover(_)) |
+        Some(EventKind::Rebound) => event.player1.as_ref(),
+        Some(EventKind::FreeThrow(FreeThrowType::Technical)) => None,
+        Some(EventKind::FreeThrow(_)) => event.player1.as_ref(),
+        Some(EventKind::JumpBall) => event.player3.as_ref(),
+        _ => None,
+    };
+    person.and_then(|x| x.side())
+}
+
+/// Possessions with no known start clock are never heaves.
+fn is_heave(possession: &Possession) -> bool {
+    match possession.start {
+        Some(start) => possession.points == 0 && start.remaining() <= HEAVE_SECONDS,
+        None => false,
+    }
+}
+
+/// Splits a game's events, in `EVENTNUM` order, into possessions.
+pub fn possessions(events: &[PlayByPlayEvent]) -> Vec<Possession> {
+    let mut events: Vec<&PlayByPlayEvent> = events.iter().collect();
+    events.sort_by_key(|x| x.eventnum);
+
+    let mut possessions = Vec::new();
+    let mut current: Option<Possession> = None;
+    let mut score = Score {
+        home: 0,
+        away: 0,
+    };
+    for event in events {
+        let period = event.period.unwrap_or(0);
+        if event.kind() == Some(EventKind::PeriodEnd) {
+            if let Some(mut possession) = current.take() {
+                possession.heave = is_heave(&possession);
+                possession.end = event.pctimestring;
+                possession.end_event = event.eventnum;
+                possessions.push(possession);
+            }
+            continue;
+        }
+
+        if let Some(side) = holder(event) {
+            let same = current.as_ref().map(|x| x.side == side).unwrap_or(false);
+            if !same {
+                // The ball changes hands where the last possession ended.
+                let start = match current.take() {
+                    Some(possession) => {
+                        let end = possession.end;
+                        possessions.push(possession);
+                        end
+                    }
+                    None => Some(GameClock::new(period_seconds(period) / 60, 0)),
+                };
+                current = Some(Possession {
+                    side: side,
+                    period: period,
+                    start: start,
+                    end: None,
+                    end_event: None,
+                    points: 0,
+                    heave: false,
+                });
+            }
+            if let Some(ref mut possession) = current {
+                possession.end = event.pctimestring;
+                possession.end_event = event.eventnum;
+            }
+        }
+
+        if let Some(new_score) = event.score {
+            let technical = event.kind() == Some(EventKind::FreeThrow(FreeThrowType::Technical));
+            if let (false, Some(possession)) = (technical, current.as_mut()) {
+                possession.points += match possession.side {
+                    Side::Home => new_score.home.saturating_sub(score.home),
+                    Side::Visitor => new_score.away.saturating_sub(score.away),
+                };
+            }
+            score = new_score;
+        }
+    }
+    if let Some(possession) = current {
+        possessions.push(possession);
+    }
+    possessions
+}
+
+
+/// Possession counts, ratings and pace for a game.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pace {
+    pub home_possessions: u64,
+    pub visitor_possessions: u64,
+    /// Final points, technical free throws included.
+    pub score: Score,
+    /// Game seconds played.
+    pub seconds: u64,
+}
+
+impl Pace {
+    pub fn from_events(events: &[PlayByPlayEvent]) -> Pace {
+        let possessions = possessions(events);
+        let count = |side| possessions.iter().filter(|x| x.side == side && !x.heave).count();
+        Pace {
+            home_possessions: count(Side::Home) as u64,
+            visitor_possessions: count(Side::Visitor) as u64,
+            score: events.iter()
+                         .filter_map(|x| x.score)
+                         .last()
+                         .unwrap_or(Score {
+                             home: 0,
+                             away: 0,
+                         }),
+            seconds: events.iter().filter_map(|x| x.elapsed()).max().unwrap_or(0),
+        }
+    }
+
+    pub fn possessions(&self, side: Side) -> u64 {
+        match side {
+            Side::Home => self.home_possessions,
+            Side::Visitor => self.visitor_possessions,
+        }
+    }
+
+    fn points(&self, side: Side) -> u64 {
+        match side {
+            Side::Home => self.score.home,
+            Side::Visitor => self.score.away,
+        }
+    }
+
+    /// Points scored per 100 possessions.
+    pub fn offensive_rating(&self, side: Side) -> Option<f64> {
+        match self.possessions(side) {
+            0 => None,
+            possessions => Some(100.0 * self.points(side) as f64 / possessions as f64),
+        }
+    }
+
+    /// Points allowed per 100 opponent possessions.
+    pub fn defensive_rating(&self, side: Side) -> Option<f64> {
+        self.offensive_rating(side.other())
+    }
+
+    /// Possessions per team per 48 minutes.
+    pub fn pace(&self) -> Option<f64> {
+        if self.seconds == 0 {
+            return None;
+        }
+        let per_team = (self.home_possessions + self.visitor_possessions) as f64 / 2.0;
+        Some(per_team * 48.0 * 60.0 / self.seconds as f64)
+    }
+}
@@ -1,3 +1,17 @@
+use teams;
+
+
+/// Value sent for a filter we don't expose. stats.nba.com rejects requests that leave
+/// any of an endpoint's filters out.
+fn unused_filter(key: &'static str) -> (&'static str, String) {
+    let value = match key {
+        "month" | "opponentteamid" | "period" | "lastngames" | "teamid" | "poround" => "0",
+        "plusminus" | "paceadjust" | "rank" => "N",
+        _ => "",
+    };
+    (key, value.to_owned())
+}
+
 #[derive(RustcDecodable, RustcEncodable, Debug)]
 pub enum Query {
     PlayByPlayQuery {
@@ -24,8 +38,255 @@ pub enum Query {
         dayoffset: String,
     },
 
+    LeagueLeadersQuery {
+        leagueid: String,
+        season: String,
+        seasontype: String,
+        permode: String,
+        statcategory: String,
+    },
+
+    /// Blank `datefrom` and `dateto` cover the whole season.
+    LeagueDashPlayerStatsQuery {
+        leagueid: String,
+        season: String,
+        seasontype: String,
+        permode: String,
+        measuretype: String,
+        datefrom: String,
+        dateto: String,
+    },
+
+    /// Blank `datefrom` and `dateto` cover the whole season.
+    LeagueDashTeamStatsQuery {
+        leagueid: String,
+        season: String,
+        seasontype: String,
+        permode: String,
+        measuretype: String,
+        datefrom: String,
+        dateto: String,
+    },
+
     TeamRosterQuery {
         season: String,
         teamid: String,
     },
+
+    BoxScoreTraditionalQuery {
+        gameid: String,
+        startperiod: String,
+        endperiod: String,
+        startrange: String,
+        endrange: String,
+        rangetype: String,
+    },
+
+    BoxScoreAdvancedQuery {
+        gameid: String,
+        startperiod: String,
+        endperiod: String,
+        startrange: String,
+        endrange: String,
+        rangetype: String,
+    },
+
+    BoxScoreFourFactorsQuery {
+        gameid: String,
+        startperiod: String,
+        endperiod: String,
+        startrange: String,
+        endrange: String,
+        rangetype: String,
+    },
+
+    /// Blank `gameid` covers every game, and a `teamid` or `playerid` of "0" every team or player.
+    ShotChartDetailQuery {
+        leagueid: String,
+        season: String,
+        seasontype: String,
+        teamid: String,
+        playerid: String,
+        gameid: String,
+    },
+
+    PlayerGameLogQuery {
+        playerid: String,
+        season: String,
+        seasontype: String,
+    },
+
+    PlayerCareerStatsQuery {
+        playerid: String,
+        permode: String,
+        leagueid: String,
+    },
+
+    CommonPlayerInfoQuery {
+        playerid: String,
+        leagueid: String,
+    },
+
+    TeamGameLogQuery {
+        teamid: String,
+        season: String,
+        seasontype: String,
+    },
+
+    /// Blank `teamid`, `datefrom` and `dateto` leave those filters off.
+    LeagueGameFinderQuery {
+        leagueid: String,
+        season: String,
+        seasontype: String,
+        teamid: String,
+        datefrom: String,
+        dateto: String,
+    },
+}
+
+impl Query {
+    /// Query string parameters sent to stats.nba.com for this query.
+    pub fn params(&self) -> Vec<(&'static str, String)> {
+        match *self {
+            Query::PlayByPlayQuery { ref gameid, ref startperiod, ref endperiod } => {
+                vec![("gameid", gameid.clone()),
+                     ("startperiod", startperiod.clone()),
+                     ("endperiod", endperiod.clone())]
+            }
+            Query::GameHeaderQuery { ref leagueid, ref gamedate, ref dayoffset } |
+            Query::EastConfStandingsQuery { ref leagueid, ref gamedate, ref dayoffset } |
+            Query::WestConfStandingsQuery { ref leagueid, ref gamedate, ref dayoffset } => {
+                vec![("leagueid", leagueid.clone()),
+                     ("gamedate", gamedate.clone()),
+                     ("dayoffset", dayoffset.clone())]
+            }
+            Query::LeagueLeadersQuery { ref leagueid,
+                                        ref season,
+                                        ref seasontype,
+                                        ref permode,
+                                        ref statcategory } => {
+                vec![("leagueid", leagueid.clone()),
+                     ("season", season.clone()),
+                     ("seasontype", seasontype.clone()),
+                     ("permode", permode.clone()),
+                     ("statcategory", statcategory.clone()),
+                     ("scope", "S".to_owned())]
+            }
+            Query::LeagueDashPlayerStatsQuery { ref leagueid,
+                                                ref season,
+                                                ref seasontype,
+                                                ref permode,
+                                                ref measuretype,
+                                                ref datefrom,
+                                                ref dateto } |
+            Query::LeagueDashTeamStatsQuery { ref leagueid,
+                                              ref season,
+                                              ref seasontype,
+                                              ref permode,
+                                              ref measuretype,
+                                              ref datefrom,
+                                              ref dateto } => {
+                let mut params = vec![("leagueid", leagueid.clone()),
+                                      ("season", season.clone()),
+                                      ("seasontype", seasontype.clone()),
+                                      ("permode", permode.clone()),
+                                      ("measuretype", measuretype.clone()),
+                                      ("datefrom", datefrom.clone()),
+                                      ("dateto", dateto.clone())];
+                for key in &["poround", "outcome", "location", "month", "seasonsegment",
+                             "opponentteamid", "vsconference", "vsdivision", "teamid",
+                             "conference", "division", "gamesegment", "period", "shotclockrange",
+                             "lastngames", "gamescope", "playerexperience", "playerposition",
+                             "starterbench", "plusminus", "paceadjust", "rank"] {
+                    params.push(unused_filter(key));
+                }
+                params
+            }
+            Query::TeamRosterQuery { ref season, ref teamid } => {
+                // Accept "CLE" or "Cavaliers" as well as the numeric team id.
+                let teamid = teams::find(teamid).map(|x| x.id.clone()).unwrap_or(teamid.clone());
+                vec![("season", season.clone()), ("teamid", teamid)]
+            }
+            Query::BoxScoreTraditionalQuery { ref gameid,
+                                              ref startperiod,
+                                              ref endperiod,
+                                              ref startrange,
+                                              ref endrange,
+                                              ref rangetype } |
+            Query::BoxScoreAdvancedQuery { ref gameid,
+                                           ref startperiod,
+                                           ref endperiod,
+                                           ref startrange,
+                                           ref endrange,
+                                           ref rangetype } |
+            Query::BoxScoreFourFactorsQuery { ref gameid,
+                                              ref startperiod,
+                                              ref endperiod,
+                                              ref startrange,
+                                              ref endrange,
+                                              ref rangetype } => {
+                vec![("gameid", gameid.clone()),
+                     ("startperiod", startperiod.clone()),
+                     ("endperiod", endperiod.clone()),
+                     ("startrange", startrange.clone()),
+                     ("endrange", endrange.clone()),
+                     ("rangetype", rangetype.clone())]
+            }
+            Query::ShotChartDetailQuery { ref leagueid,
+                                          ref season,
+                                          ref seasontype,
+                                          ref teamid,
+                                          ref playerid,
+                                          ref gameid } => {
+                let teamid = teams::find(teamid).map(|x| x.id.clone()).unwrap_or(teamid.clone());
+                let mut params = vec![("leagueid", leagueid.clone()),
+                                      ("season", season.clone()),
+                                      ("seasontype", seasontype.clone()),
+                                      ("teamid", teamid),
+                                      ("playerid", playerid.clone()),
+                                      ("gameid", gameid.clone()),
+                                      ("contextmeasure", "FGA".to_owned())];
+                for key in &["outcome", "location", "month", "seasonsegment", "datefrom",
+                             "dateto", "opponentteamid", "vsconference", "vsdivision",
+                             "position", "rookieyear", "gamesegment", "period", "lastngames"] {
+                    params.push(unused_filter(key));
+                }
+                params
+            }
+            Query::PlayerGameLogQuery { ref playerid, ref season, ref seasontype } => {
+                vec![("playerid", playerid.clone()),
+                     ("season", season.clone()),
+                     ("seasontype", seasontype.clone())]
+            }
+            Query::PlayerCareerStatsQuery { ref playerid, ref permode, ref leagueid } => {
+                vec![("playerid", playerid.clone()),
+                     ("permode", permode.clone()),
+                     ("leagueid", leagueid.clone())]
+            }
+            Query::CommonPlayerInfoQuery { ref playerid, ref leagueid } => {
+                vec![("playerid", playerid.clone()), ("leagueid", leagueid.clone())]
+            }
+            Query::TeamGameLogQuery { ref teamid, ref season, ref seasontype } => {
+                let teamid = teams::find(teamid).map(|x| x.id.clone()).unwrap_or(teamid.clone());
+                vec![("teamid", teamid),
+                     ("season", season.clone()),
+                     ("seasontype", seasontype.clone())]
+            }
+            Query::LeagueGameFinderQuery { ref leagueid,
+                                           ref season,
+                                           ref seasontype,
+                                           ref teamid,
+                                           ref datefrom,
+                                           ref dateto } => {
+                let teamid = teams::find(teamid).map(|x| x.id.clone()).unwrap_or(teamid.clone());
+                vec![("playerorteam", "T".to_owned()),
+                     ("leagueid", leagueid.clone()),
+                     ("season", season.clone()),
+                     ("seasontype", seasontype.clone()),
+                     ("teamid", teamid),
+                     ("datefrom", datefrom.clone()),
+                     ("dateto", dateto.clone())]
+            }
+        }
+    }
 }
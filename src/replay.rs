@@ -0,0 +1,340 @@
+use std::collections::{BTreeMap, HashSet};
+use events::{EventKind, FoulType, FreeThrowType};
+use clock::GameClock;
+use stats::{PlayByPlayEvent, EventPerson, Score};
+
+
+/// Full timeouts each team starts a game with under the 2015-16 rules.
+pub static GAME_TIMEOUTS: u64 = 6;
+/// Twenty-second timeouts each team starts a game with. Unused ones carry into overtime.
+pub static SHORT_TIMEOUTS: u64 = 2;
+/// Full timeouts each team has in every overtime period, however many were left.
+pub static OVERTIME_TIMEOUTS: u64 = 3;
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Side {
+    Home,
+    Visitor,
+}
+
+impl Side {
+    pub fn other(&self) -> Side {
+        match *self {
+            Side::Home => Side::Visitor,
+            Side::Visitor => Side::Home,
+        }
+    }
+}
+
+impl EventPerson {
+    /// Which team the player or team is on, from `PERSONnTYPE`.
+    pub fn side(&self) -> Option<Side> {
+        if self.is_home() {
+            Some(Side::Home)
+        } else if self.is_visitor() {
+            Some(Side::Visitor)
+        } else {
+            None
+        }
+    }
+}
+
+
+/// One team's side of the game state.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TeamState {
+    /// Fouls counting toward the penalty in the current period.
+    pub fouls: u64,
+    /// Full timeouts left.
+    pub timeouts: u64,
+    /// Twenty-second timeouts left.
+    pub short_timeouts: u64,
+    /// Player ids of the players on court.
+    pub on_court: Vec<String>,
+}
+
+impl TeamState {
+    fn new() -> TeamState {
+        TeamState {
+            fouls: 0,
+            timeouts: GAME_TIMEOUTS,
+            short_timeouts: SHORT_TIMEOUTS,
+            on_court: Vec::new(),
+        }
+    }
+}
+
+/// The state of a game right after an event.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameState {
+    pub score: Score,
+    pub period: u64,
+    pub clock: Option<GameClock>,
+    pub home: TeamState,
+    pub visitor: TeamState,
+    /// The team with the ball, `None` between periods and before the opening tip.
+    pub possession: Option<Side>,
+}
+
+impl GameState {
+    pub fn new() -> GameState {
+        GameState {
+            score: Score {
+                home: 0,
+                away: 0,
+            },
+            period: 0,
+            clock: None,
+            home: TeamState::new(),
+            visitor: TeamState::new(),
+            possession: None,
+        }
+    }
+
+    pub fn team(&self, side: Side) -> &TeamState {
+        match side {
+            Side::Home => &self.home,
+            Side::Visitor => &self.visitor,
+        }
+    }
+
+    fn team_mut(&mut self, side: Side) -> &mut TeamState {
+        match side {
+            Side::Home => &mut self.home,
+            Side::Visitor => &mut self.visitor,
+        }
+    }
+
+    fn apply(&mut self, event: &PlayByPlayEvent, starters: &BTreeMap<u64, Lineups>) {
+        if let Some(score) = event.score {
+            self.score = score;
+        }
+        if let Some(period) = event.period {
+            self.period = period;
+        }
+        if event.pctimestring.is_some() {
+            self.clock = event.pctimestring;
+        }
+        let kind = match event.kind() {
+            Some(kind) => kind,
+            None => return,
+        };
+        let side = event.player1.as_ref().and_then(|x| x.side());
+
+        match kind {
+            EventKind::PeriodStart => {
+                self.possession = None;
+                self.home.fouls = 0;
+                self.visitor.fouls = 0;
+                if self.period > 4 {
+                    self.home.timeouts = OVERTIME_TIMEOUTS;
+                    self.visitor.timeouts = OVERTIME_TIMEOUTS;
+                }
+                if let Some(lineups) = starters.get(&self.period) {
+                    self.home.on_court = lineups.fill(&lineups.home, &self.home.on_court);
+                    self.visitor.on_court = lineups.fill(&lineups.visitor,
+                                                         &self.visitor.on_court);
+                }
+            }
+            EventKind::PeriodEnd => self.possession = None,
+            EventKind::JumpBall => {
+                if let Some(side) = event.player3.as_ref().and_then(|x| x.side()) {
+                    self.possession = Some(side);
+                }
+            }
+            EventKind::MadeShot(_) | EventKind::Turnover(_) => {
+                self.possession = side.map(|x| x.other());
+            }
+            EventKind::MissedShot(_) | EventKind::Rebound => self.possession = side,
+            // The fouled team keeps the ball after flagrant and clear path free throws.
+            EventKind::FreeThrow(FreeThrowType::Flagrant { .. }) |
+            EventKind::FreeThrow(FreeThrowType::ClearPath { .. }) => {}
+            EventKind::FreeThrow(free_throw) => {
+                if free_throw.is_last() && !event.details.missed {
+                    self.possession = side.map(|x| x.other());
+                } else if free_throw.is_last() {
+                    self.possession = side;
+                }
+            }
+            EventKind::Foul(foul) => {
+                if let Some(side) = side {
+                    if foul.is_team_foul() {
+                        self.team_mut(side).fouls += 1;
+                    }
+                }
+            }
+            EventKind::Timeout => {
+                // Action types 1 and 2 are full and short timeouts; official ones are free.
+                if let Some(side) = side {
+                    let team = self.team_mut(side);
+                    match event.eventmsgactiontype {
+                        Some(1) => team.timeouts = team.timeouts.saturating_sub(1),
+                        Some(2) => team.short_timeouts = team.short_timeouts.saturating_sub(1),
+                        _ => {}
+                    }
+                }
+            }
+            EventKind::Substitution => {
+                let (out, into) = match (event.player1.as_ref(), event.player2.as_ref()) {
+                    (Some(out), Some(into)) => (out, into),
+                    _ => return,
+                };
+                if let Some(side) = out.side() {
+                    let seen = starters.get(&self.period).map(|x| &x.seen);
+                    let team = self.team_mut(side);
+                    let before = team.on_court.len();
+                    team.on_court.retain(|x| *x != out.id);
+                    if team.on_court.contains(&into.id) {
+                        return;
+                    }
+                    if team.on_court.len() < before {
+                        team.on_court.push(into.id.clone());
+                        return;
+                    }
+                    // The player going out wasn't picked up as on court, so one of the
+                    // players carried over from the last period must be wrong. Swap out one
+                    // who does nothing in this period.
+                    let unseen = team.on_court
+                                     .iter()
+                                     .position(|x| seen.map_or(true, |seen| !seen.contains(x)));
+                    match unseen {
+                        Some(n) => team.on_court[n] = into.id.clone(),
+                        None if team.on_court.len() < 5 => team.on_court.push(into.id.clone()),
+                        None => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+
+/// The players on court for each side at the start of a period, as far as the period's
+/// events tell.
+#[derive(Debug, Clone, PartialEq)]
+struct Lineups {
+    home: Vec<String>,
+    visitor: Vec<String>,
+    /// Everyone taking part in the period.
+    seen: HashSet<String>,
+}
+
+impl Lineups {
+    fn new() -> Lineups {
+        Lineups {
+            home: Vec::new(),
+            visitor: Vec::new(),
+            seen: HashSet::new(),
+        }
+    }
+
+    /// Tops up a short lineup with players from the end of the previous period who didn't
+    /// take part in this one.
+    fn fill(&self, starters: &[String], previous: &[String]) -> Vec<String> {
+        let mut lineup = starters.to_vec();
+        for id in previous {
+            if lineup.len() >= 5 {
+                break;
+            }
+            if !self.seen.contains(id) {
+                lineup.push(id.clone());
+            }
+        }
+        lineup
+    }
+}
+
+/// Whether the players named in an event are on court for it. Bench technicals and
+/// ejections can name players sitting down.
+fn on_court_event(kind: EventKind) -> bool {
+    match kind {
+        EventKind::MadeShot(_) |
+        EventKind::MissedShot(_) |
+        EventKind::FreeThrow(_) |
+        EventKind::Rebound |
+        EventKind::Turnover(_) |
+        EventKind::Violation(_) |
+        EventKind::JumpBall |
+        EventKind::Substitution => true,
+        EventKind::Foul(foul) => foul != FoulType::Technical,
+        _ => false,
+    }
+}
+
+/// Works out who started each period, since play-by-play doesn't say. A player started a
+/// period if they show up in it before being subbed in.
+fn period_starters(events: &[&PlayByPlayEvent]) -> BTreeMap<u64, Lineups> {
+    let mut starters = BTreeMap::new();
+    for event in events {
+        let (kind, period) = match (event.kind(), event.period) {
+            (Some(kind), Some(period)) if on_court_event(kind) => (kind, period),
+            _ => continue,
+        };
+        let lineups = starters.entry(period).or_insert_with(Lineups::new);
+        let people = [&event.player1, &event.player2, &event.player3];
+        for (n, person) in people.iter().enumerate() {
+            let person = match **person {
+                Some(ref person) if !person.is_team() => person,
+                _ => continue,
+            };
+            // The second player of a substitution is coming in.
+            let subbed_in = kind == EventKind::Substitution && n == 1;
+            if lineups.seen.insert(person.id.clone()) && !subbed_in {
+                match person.side() {
+                    Some(Side::Home) => lineups.home.push(person.id.clone()),
+                    Some(Side::Visitor) => lineups.visitor.push(person.id.clone()),
+                    None => {}
+                }
+            }
+        }
+    }
+    starters
+}
+
+
+/// Steps through a game's events in `EVENTNUM` order, with the game state after each.
+///
+/// Play-by-play doesn't list who is on court, so lineups are inferred from who takes part
+/// in each period. A starter who does nothing in a period is only picked up if they were on
+/// court at the end of the previous one.
+pub struct Replay<'a> {
+    events: Vec<&'a PlayByPlayEvent>,
+    starters: BTreeMap<u64, Lineups>,
+    state: GameState,
+    next: usize,
+}
+
+impl<'a> Replay<'a> {
+    pub fn new(events: &'a [PlayByPlayEvent]) -> Replay<'a> {
+        let mut events: Vec<&PlayByPlayEvent> = events.iter().collect();
+        events.sort_by_key(|x| x.eventnum);
+        let starters = period_starters(&events);
+        Replay {
+            events: events,
+            starters: starters,
+            state: GameState::new(),
+            next: 0,
+        }
+    }
+
+    /// The state after the last event returned.
+    pub fn state(&self) -> &GameState {
+        &self.state
+    }
+}
+
+impl<'a> Iterator for Replay<'a> {
+    type Item = (&'a PlayByPlayEvent, GameState);
+
+    fn next(&mut self) -> Option<(&'a PlayByPlayEvent, GameState)> {
+        let event = match self.events.get(self.next) {
+            Some(event) => *event,
+            None => return None,
+        };
+        self.next += 1;
+        self.state.apply(event, &self.starters);
+        Some((event, self.state.clone()))
+    }
+}
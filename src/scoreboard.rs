@@ -0,0 +1,91 @@
+use stats::{GameHeader, LineScore, StandingsRow, SeriesStanding, LastMeeting, Availability};
+use decode::{ResultSet, find_result_set};
+use endpoint::fetch_result_sets_with;
+use constants::*;
+use err::NBAError;
+use queries::Query;
+use transport::{Transport, HyperTransport};
+
+
+/// Everything the scoreboard endpoint returns for a day, from a single request.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Scoreboard {
+    pub game_header: Vec<GameHeader>,
+    pub line_score: Vec<LineScore>,
+    pub series_standings: Vec<SeriesStanding>,
+    pub last_meeting: Vec<LastMeeting>,
+    pub east_conf_standings: Vec<StandingsRow>,
+    pub west_conf_standings: Vec<StandingsRow>,
+    pub available: Vec<Availability>,
+}
+
+impl Scoreboard {
+    pub fn from_result_sets(sets: &[ResultSet]) -> Result<Scoreboard, NBAError> {
+        Ok(Scoreboard {
+            game_header: try!(try!(find_result_set(sets, "GameHeader")).decode()),
+            line_score: try!(try!(find_result_set(sets, "LineScore")).decode()),
+            series_standings: try!(try!(find_result_set(sets, "SeriesStandings")).decode()),
+            last_meeting: try!(try!(find_result_set(sets, "LastMeeting")).decode()),
+            east_conf_standings: try!(try!(find_result_set(sets, "EastConfStandingsByDay"))
+                                          .decode()),
+            west_conf_standings: try!(try!(find_result_set(sets, "WestConfStandingsByDay"))
+                                          .decode()),
+            available: try!(try!(find_result_set(sets, "Available")).decode()),
+        })
+    }
+
+    /// Fetches a day's scoreboard. Takes any of the queries served by the scoreboard
+    /// endpoint, e.g. a `GameHeaderQuery`.
+    pub fn fetch(payload: Query) -> Result<Scoreboard, NBAError> {
+        Scoreboard::fetch_with(&HyperTransport::new(), payload)
+    }
+
+    pub fn fetch_with(transport: &Transport, payload: Query) -> Result<Scoreboard, NBAError> {
+        match payload {
+            Query::GameHeaderQuery { .. } |
+            Query::EastConfStandingsQuery { .. } |
+            Query::WestConfStandingsQuery { .. } => {}
+            _ => {
+                return Err(NBAError::InvalidQuery {
+                    field: "query",
+                    value: format!("{:?}", payload),
+                    expected: "a scoreboard query",
+                })
+            }
+        }
+        let sets = try!(fetch_result_sets_with(transport, GAMEHEADER_BASE_URL, payload));
+        Scoreboard::from_result_sets(&sets)
+    }
+
+    pub fn game(&self, game_id: &str) -> Option<&GameHeader> {
+        self.game_header.iter().find(|x| x.game_id.as_ref().map(|x| &x[..]) == Some(game_id))
+    }
+
+    /// The home and visiting teams' line scores for a game.
+    pub fn line_scores(&self, game_id: &str) -> Option<(&LineScore, &LineScore)> {
+        let game = match self.game(game_id) {
+            Some(game) => game,
+            None => return None,
+        };
+        let find = |team_id: &Option<String>| {
+            if team_id.is_none() {
+                return None;
+            }
+            self.line_score.iter().find(|x| x.game_id == game.game_id && x.team_id == *team_id)
+        };
+        match (find(&game.home_team_id), find(&game.visitor_team_id)) {
+            (Some(home), Some(visitor)) => Some((home, visitor)),
+            _ => None,
+        }
+    }
+
+    /// The current score of a game as (home, visitor) points.
+    pub fn score(&self, game_id: &str) -> Option<(u64, u64)> {
+        self.line_scores(game_id).and_then(|(home, visitor)| {
+            match (home.pts, visitor.pts) {
+                (Some(home), Some(visitor)) => Some((home, visitor)),
+                _ => None,
+            }
+        })
+    }
+}
@@ -1,141 +1,406 @@
-use rustc_serialize::json;
 use serde_json;
 use serde_json::Value;
 use regex::Regex;
-use hyper::{Client, Url};
-use hyper::header::{Referer, UserAgent};
-use std::io::Read;
-use std::collections::HashMap;
+use hyper::Url;
 use stats::{StatType, Stat};
-use parse::*;
+use endpoint::*;
 use constants::*;
 use err::NBAError;
 use queries::Query;
+use teams;
+use transport::{Transport, HyperTransport};
 
 
 pub trait Scrape {
-    fn check_payload(payload: &Query);
+    fn check_payload(payload: &Query) -> Result<(), NBAError>;
     fn post_query(base_url: String, payload: Query) -> Result<Value, NBAError>;
+    fn post_query_with(transport: &Transport,
+                       base_url: String,
+                       payload: Query)
+                       -> Result<Value, NBAError>;
     fn get_data(stat: StatType, payload: Query) -> Result<Vec<Stat>, NBAError>;
+    fn get_data_with(transport: &Transport,
+                     stat: StatType,
+                     payload: Query)
+                     -> Result<Vec<Stat>, NBAError>;
 }
 
 
+fn validate(field: &'static str,
+            value: &str,
+            pattern: &str,
+            expected: &'static str)
+            -> Result<(), NBAError> {
+    let re = try!(Regex::new(pattern).map_err(|_| NBAError::RegexError));
+    if re.is_match(value) {
+        Ok(())
+    } else {
+        Err(NBAError::InvalidQuery {
+            field: field,
+            value: value.to_owned(),
+            expected: expected,
+        })
+    }
+}
+
+fn validate_league(leagueid: &str) -> Result<(), NBAError> {
+    validate("leagueid", leagueid, r"^(00|10|20)$", "a league id of 00, 10 or 20")
+}
+
+fn validate_season(season: &str) -> Result<(), NBAError> {
+    validate("season", season, r"^\d{4}-\d{2}$", "a season formatted YYYY-YY")
+}
+
+fn validate_season_type(seasontype: &str) -> Result<(), NBAError> {
+    validate("seasontype",
+             seasontype,
+             r"^(Regular Season|Playoffs|Pre Season|All Star)$",
+             "Regular Season, Playoffs, Pre Season or All Star")
+}
+
+
+/// Columns `leagueleaders` can rank by.
+static STAT_CATEGORIES: &'static [&'static str] = &["MIN", "FGM", "FGA", "FG_PCT", "FG3M", "FG3A",
+                                                    "FG3_PCT", "FTM", "FTA", "FT_PCT", "OREB",
+                                                    "DREB", "REB", "AST", "STL", "BLK", "TOV",
+                                                    "PTS", "EFF", "AST_TOV", "STL_TOV"];
+
+
 impl Scrape for Stat {
-    fn check_payload(payload: &Query) {
+    fn check_payload(payload: &Query) -> Result<(), NBAError> {
         match payload {
             &Query::PlayByPlayQuery { ref gameid, ref startperiod, ref endperiod } => {
-                let gameid_re = Regex::new(r"\d{10}").unwrap();
-                let startperiod_re = Regex::new(r"[0-9]|1[0-4]").unwrap();
-                let endperiod_re = Regex::new(r"[0-9]|1[0-4]").unwrap();
-                assert!(gameid_re.is_match(gameid));
-                assert!(startperiod_re.is_match(startperiod));
-                assert!(endperiod_re.is_match(endperiod));
-            }
-            &Query::GameHeaderQuery { ref leagueid, ref gamedate, ref dayoffset } => {
-                let leagueid_re = Regex::new(r"00|01").unwrap();
-                let gamedate_re = Regex::new(r"^\d{2}/\d{2}/\d{4}$").unwrap();
-                let dayoffset_re = Regex::new(r"\d{1}|\d{2}").unwrap();
-                assert!(leagueid_re.is_match(leagueid));
-                assert!(gamedate_re.is_match(gamedate));
-                assert!(dayoffset_re.is_match(dayoffset));
-
-            }
-
-            &Query::EastConfStandingsQuery { ref leagueid, ref gamedate, ref dayoffset } => {
-                let leagueid_re = Regex::new(r"00|01").unwrap();
-                let gamedate_re = Regex::new(r"^\d{2}/\d{2}/\d{4}$").unwrap();
-                let dayoffset_re = Regex::new(r"\d{1}|\d{2}").unwrap();
-                assert!(leagueid_re.is_match(leagueid));
-                assert!(gamedate_re.is_match(gamedate));
-                assert!(dayoffset_re.is_match(dayoffset));
+                try!(validate("gameid", gameid, r"^\d{10}$", "a 10 digit game id"));
+                try!(validate("startperiod",
+                              startperiod,
+                              r"^([0-9]|1[0-4])$",
+                              "a period between 0 and 14"));
+                try!(validate("endperiod",
+                              endperiod,
+                              r"^([0-9]|1[0-4])$",
+                              "a period between 0 and 14"));
             }
+            &Query::GameHeaderQuery { ref leagueid, ref gamedate, ref dayoffset } |
+            &Query::EastConfStandingsQuery { ref leagueid, ref gamedate, ref dayoffset } |
             &Query::WestConfStandingsQuery { ref leagueid, ref gamedate, ref dayoffset } => {
-                let leagueid_re = Regex::new(r"00|01").unwrap();
-                let gamedate_re = Regex::new(r"^\d{2}/\d{2}/\d{4}$").unwrap();
-                let dayoffset_re = Regex::new(r"\d{1}|\d{2}").unwrap();
-                assert!(leagueid_re.is_match(leagueid));
-                assert!(gamedate_re.is_match(gamedate));
-                assert!(dayoffset_re.is_match(dayoffset));
+                try!(validate_league(leagueid));
+                try!(validate("gamedate",
+                              gamedate,
+                              r"^\d{2}/\d{2}/\d{4}$",
+                              "a date formatted MM/DD/YYYY"));
+                try!(validate("dayoffset", dayoffset, r"^\d{1,2}$", "a one or two digit offset"));
+            }
+            &Query::LeagueLeadersQuery { ref leagueid,
+                                         ref season,
+                                         ref seasontype,
+                                         ref permode,
+                                         ref statcategory } => {
+                try!(validate_league(leagueid));
+                try!(validate_season(season));
+                try!(validate_season_type(seasontype));
+                try!(validate("permode",
+                              permode,
+                              r"^(Totals|PerGame|Per36|Per100Possessions)$",
+                              "Totals, PerGame, Per36 or Per100Possessions"));
+                if !STAT_CATEGORIES.contains(&&statcategory[..]) {
+                    return Err(NBAError::InvalidQuery {
+                        field: "statcategory",
+                        value: statcategory.clone(),
+                        expected: "a stat category such as PTS, REB or AST",
+                    });
+                }
+            }
+            &Query::LeagueDashPlayerStatsQuery { ref leagueid,
+                                                 ref season,
+                                                 ref seasontype,
+                                                 ref permode,
+                                                 ref measuretype,
+                                                 ref datefrom,
+                                                 ref dateto } |
+            &Query::LeagueDashTeamStatsQuery { ref leagueid,
+                                               ref season,
+                                               ref seasontype,
+                                               ref permode,
+                                               ref measuretype,
+                                               ref datefrom,
+                                               ref dateto } => {
+                try!(validate_league(leagueid));
+                try!(validate_season(season));
+                try!(validate_season_type(seasontype));
+                try!(validate("permode",
+                              permode,
+                              r"^(Totals|PerGame|Per36|Per100Possessions)$",
+                              "Totals, PerGame, Per36 or Per100Possessions"));
+                try!(validate("measuretype",
+                              measuretype,
+                              r"^(Base|Advanced|Misc|Four Factors|Scoring|Opponent|Usage|Defense)$",
+                              "Base, Advanced, Misc, Four Factors, Scoring, Opponent, Usage or \
+                               Defense"));
+                try!(validate("datefrom",
+                              datefrom,
+                              r"^(\d{2}/\d{2}/\d{4})?$",
+                              "a date formatted MM/DD/YYYY or blank"));
+                try!(validate("dateto",
+                              dateto,
+                              r"^(\d{2}/\d{2}/\d{4})?$",
+                              "a date formatted MM/DD/YYYY or blank"));
             }
             &Query::TeamRosterQuery { ref season, ref teamid } => {
-                let season_re = Regex::new(r"^\d{4}-\d{2}$").unwrap();
-                let team_id_re = Regex::new(r"\d{10}").unwrap();
-                assert!(season_re.is_match(season));
-                assert!(team_id_re.is_match(teamid));
+                try!(validate_season(season));
+                if teams::find(teamid).is_none() {
+                    try!(validate("teamid",
+                                  teamid,
+                                  r"^\d{10}$",
+                                  "a 10 digit team id or a known team"));
+                }
+            }
+            &Query::BoxScoreTraditionalQuery { ref gameid,
+                                               ref startperiod,
+                                               ref endperiod,
+                                               ref startrange,
+                                               ref endrange,
+                                               ref rangetype } |
+            &Query::BoxScoreAdvancedQuery { ref gameid,
+                                            ref startperiod,
+                                            ref endperiod,
+                                            ref startrange,
+                                            ref endrange,
+                                            ref rangetype } |
+            &Query::BoxScoreFourFactorsQuery { ref gameid,
+                                               ref startperiod,
+                                               ref endperiod,
+                                               ref startrange,
+                                               ref endrange,
+                                               ref rangetype } => {
+                try!(validate("gameid", gameid, r"^\d{10}$", "a 10 digit game id"));
+                try!(validate("startperiod",
+                              startperiod,
+                              r"^([0-9]|1[0-4])$",
+                              "a period between 0 and 14"));
+                try!(validate("endperiod",
+                              endperiod,
+                              r"^([0-9]|1[0-4])$",
+                              "a period between 0 and 14"));
+                try!(validate("startrange",
+                              startrange,
+                              r"^\d{1,5}$",
+                              "a number of tenths of a second"));
+                try!(validate("endrange",
+                              endrange,
+                              r"^\d{1,5}$",
+                              "a number of tenths of a second"));
+                try!(validate("rangetype", rangetype, r"^[0-2]$", "a range type of 0, 1 or 2"));
+            }
+            &Query::ShotChartDetailQuery { ref leagueid,
+                                           ref season,
+                                           ref seasontype,
+                                           ref teamid,
+                                           ref playerid,
+                                           ref gameid } => {
+                try!(validate_league(leagueid));
+                try!(validate_season(season));
+                try!(validate_season_type(seasontype));
+                if teams::find(teamid).is_none() {
+                    try!(validate("teamid",
+                                  teamid,
+                                  r"^(0|\d{10})$",
+                                  "0, a 10 digit team id or a known team"));
+                }
+                try!(validate("playerid", playerid, r"^\d+$", "a numeric player id"));
+                try!(validate("gameid", gameid, r"^(\d{10})?$", "a 10 digit game id or blank"));
+            }
+            &Query::PlayerGameLogQuery { ref playerid, ref season, ref seasontype } => {
+                try!(validate("playerid", playerid, r"^\d+$", "a numeric player id"));
+                try!(validate_season(season));
+                try!(validate_season_type(seasontype));
+            }
+            &Query::PlayerCareerStatsQuery { ref playerid, ref permode, ref leagueid } => {
+                try!(validate("playerid", playerid, r"^\d+$", "a numeric player id"));
+                try!(validate("permode",
+                              permode,
+                              r"^(Totals|PerGame|Per36)$",
+                              "Totals, PerGame or Per36"));
+                try!(validate_league(leagueid));
+            }
+            &Query::CommonPlayerInfoQuery { ref playerid, ref leagueid } => {
+                try!(validate("playerid", playerid, r"^\d+$", "a numeric player id"));
+                try!(validate_league(leagueid));
+            }
+            &Query::TeamGameLogQuery { ref teamid, ref season, ref seasontype } => {
+                if teams::find(teamid).is_none() {
+                    try!(validate("teamid",
+                                  teamid,
+                                  r"^\d{10}$",
+                                  "a 10 digit team id or a known team"));
+                }
+                try!(validate_season(season));
+                try!(validate_season_type(seasontype));
+            }
+            &Query::LeagueGameFinderQuery { ref leagueid,
+                                            ref season,
+                                            ref seasontype,
+                                            ref teamid,
+                                            ref datefrom,
+                                            ref dateto } => {
+                try!(validate_league(leagueid));
+                try!(validate_season(season));
+                try!(validate_season_type(seasontype));
+                if teams::find(teamid).is_none() {
+                    try!(validate("teamid",
+                                  teamid,
+                                  r"^(\d{10})?$",
+                                  "a 10 digit team id, a known team or blank"));
+                }
+                try!(validate("datefrom",
+                              datefrom,
+                              r"^(\d{2}/\d{2}/\d{4})?$",
+                              "a date formatted MM/DD/YYYY or blank"));
+                try!(validate("dateto",
+                              dateto,
+                              r"^(\d{2}/\d{2}/\d{4})?$",
+                              "a date formatted MM/DD/YYYY or blank"));
             }
         }
+        Ok(())
     }
     fn post_query(base_url: String, payload: Query) -> Result<Value, NBAError> {
-        let client = Client::new();
-        let mut url = try!(Url::parse(&base_url));
+        Stat::post_query_with(&HyperTransport::new(), base_url, payload)
+    }
 
-        let s = try!(json::encode(&payload));
-        let dict: HashMap<String, String> = try!(json::decode(&s));
-        for (key, value) in dict {
-            url.query_pairs_mut().append_pair(&key, &value);
+    fn post_query_with(transport: &Transport,
+                       base_url: String,
+                       payload: Query)
+                       -> Result<Value, NBAError> {
+        let mut url = try!(Url::parse(&base_url));
+        for (key, value) in payload.params() {
+            url.query_pairs_mut().append_pair(key, &value);
         }
 
-        let referer = REFERER.to_owned();
-        let user_agent = USERAGENT.to_owned();
-
-        let mut response = try!(client.get(url)
-                                      .header(Referer(referer))
-                                      .header(UserAgent(user_agent))
-                                      .send());
+        let headers = vec![("Referer".to_owned(), REFERER.to_owned()),
+                           ("User-Agent".to_owned(), USERAGENT.to_owned())];
 
-        let body: String = {
-            let mut s = String::new();
-            let _ = response.read_to_string(&mut s);
-            s
-        };
+        let response = try!(transport.send(&url, &headers));
+        if response.status < 200 || response.status >= 300 {
+            return Err(NBAError::HttpStatusError(response.status));
+        }
 
-        let data: Value = try!(serde_json::from_str(&body));
+        let data: Value = try!(serde_json::from_str(&response.body));
         Ok(data)
 
     }
 
     fn get_data(stat: StatType, payload: Query) -> Result<Vec<Stat>, NBAError> {
-        Stat::check_payload(&payload);
-        let base_url = match stat {
-            StatType::PlayByPlay => PLAYBYPLAY_BASE_URL,
-            StatType::GameHeader => GAMEHEADER_BASE_URL,
-            StatType::EastConfStandings => EASTCONFSTANDINGS_BASE_URL,
-            StatType::WestConfStandings => WESTCONFSTANDINGS_BASE_URL,
-            StatType::TeamRoster => TEAMROSTER_BASE_URL,
-
-        };
-
-        let data: Value = try!(Stat::post_query(base_url.to_owned(), payload));
-        let data = data.as_object().unwrap();
-
-        let data = data.get("resultSets")
-                       .ok_or(NBAError::MissingField("resultSets"))
-                       .unwrap()
-                       .as_array()
-                       .unwrap();
-
-        let data = match stat {
-            StatType::PlayByPlay => data[0].as_object().unwrap(),
-            StatType::GameHeader => data[0].as_object().unwrap(),
-            StatType::EastConfStandings => data[4].as_object().unwrap(),
-            StatType::WestConfStandings => data[5].as_object().unwrap(),
-            StatType::TeamRoster => data[0].as_object().unwrap(),
-
-        };
-        let headers = data.get("headers").ok_or(NBAError::MissingField("headers")).unwrap();
-        let headers = headers.as_array().unwrap();
-        let data = data.get("rowSet").ok_or(NBAError::MissingField("rowSet")).unwrap();
-        let rows = data.as_array().unwrap();
+        Stat::get_data_with(&HyperTransport::new(), stat, payload)
+    }
 
+    fn get_data_with(transport: &Transport,
+                     stat: StatType,
+                     payload: Query)
+                     -> Result<Vec<Stat>, NBAError> {
         match stat {
-            StatType::PlayByPlay => parse_playbyplay(&headers, &rows),
-            StatType::GameHeader => parse_gameheader(&headers, &rows),
-            StatType::EastConfStandings => parse_eastconfstandings(&headers, &rows),
-            StatType::WestConfStandings => parse_westconfstandings(&headers, &rows),
-            StatType::TeamRoster => parse_teamroster(&headers, &rows),
-
+            StatType::PlayByPlay => {
+                let rows = try!(fetch_with::<PlayByPlayEndpoint>(transport, payload));
+                Ok(rows.into_iter().map(Stat::PlayByPlay).collect())
+            }
+            StatType::GameHeader => {
+                let rows = try!(fetch_with::<GameHeaderEndpoint>(transport, payload));
+                Ok(rows.into_iter().map(Stat::GameHeader).collect())
+            }
+            StatType::LineScore => {
+                let rows = try!(fetch_with::<LineScoreEndpoint>(transport, payload));
+                Ok(rows.into_iter().map(Stat::LineScore).collect())
+            }
+            StatType::EastConfStandings => {
+                let rows = try!(fetch_with::<EastConfStandingsEndpoint>(transport, payload));
+                Ok(rows.into_iter().map(Stat::EastConfStandings).collect())
+            }
+            StatType::WestConfStandings => {
+                let rows = try!(fetch_with::<WestConfStandingsEndpoint>(transport, payload));
+                Ok(rows.into_iter().map(Stat::WestConfStandings).collect())
+            }
+            StatType::LeagueLeaders => {
+                let rows = try!(fetch_with::<LeagueLeadersEndpoint>(transport, payload));
+                Ok(rows.into_iter().map(Stat::LeagueLeaders).collect())
+            }
+            StatType::LeagueDashPlayerStats => {
+                let rows = try!(fetch_with::<LeagueDashPlayerStatsEndpoint>(transport, payload));
+                Ok(rows.into_iter().map(Stat::LeagueDashPlayerStats).collect())
+            }
+            StatType::LeagueDashTeamStats => {
+                let rows = try!(fetch_with::<LeagueDashTeamStatsEndpoint>(transport, payload));
+                Ok(rows.into_iter().map(Stat::LeagueDashTeamStats).collect())
+            }
+            StatType::TeamRoster => {
+                let rows = try!(fetch_with::<TeamRosterEndpoint>(transport, payload));
+                Ok(rows.into_iter().map(Stat::TeamRoster).collect())
+            }
+            StatType::BoxScoreTraditionalPlayers => {
+                let rows = try!(fetch_with::<BoxScoreTraditionalPlayersEndpoint>(transport,
+                                                                                 payload));
+                Ok(rows.into_iter().map(Stat::BoxScoreTraditionalPlayer).collect())
+            }
+            StatType::BoxScoreTraditionalTeams => {
+                let rows = try!(fetch_with::<BoxScoreTraditionalTeamsEndpoint>(transport, payload));
+                Ok(rows.into_iter().map(Stat::BoxScoreTraditionalTeam).collect())
+            }
+            StatType::BoxScoreAdvancedPlayers => {
+                let rows = try!(fetch_with::<BoxScoreAdvancedPlayersEndpoint>(transport, payload));
+                Ok(rows.into_iter().map(Stat::BoxScoreAdvancedPlayer).collect())
+            }
+            StatType::BoxScoreAdvancedTeams => {
+                let rows = try!(fetch_with::<BoxScoreAdvancedTeamsEndpoint>(transport, payload));
+                Ok(rows.into_iter().map(Stat::BoxScoreAdvancedTeam).collect())
+            }
+            StatType::BoxScoreFourFactorsPlayers => {
+                let rows = try!(fetch_with::<BoxScoreFourFactorsPlayersEndpoint>(transport,
+                                                                                 payload));
+                Ok(rows.into_iter().map(Stat::BoxScoreFourFactorsPlayer).collect())
+            }
+            StatType::BoxScoreFourFactorsTeams => {
+                let rows = try!(fetch_with::<BoxScoreFourFactorsTeamsEndpoint>(transport, payload));
+                Ok(rows.into_iter().map(Stat::BoxScoreFourFactorsTeam).collect())
+            }
+            StatType::ShotChart => {
+                let rows = try!(fetch_with::<ShotChartEndpoint>(transport, payload));
+                Ok(rows.into_iter().map(Stat::Shot).collect())
+            }
+            StatType::ShotChartLeagueAverages => {
+                let rows = try!(fetch_with::<ShotChartLeagueAveragesEndpoint>(transport, payload));
+                Ok(rows.into_iter().map(Stat::ShotZoneAverage).collect())
+            }
+            StatType::PlayerGameLog => {
+                let rows = try!(fetch_with::<PlayerGameLogEndpoint>(transport, payload));
+                Ok(rows.into_iter().map(Stat::PlayerGameLog).collect())
+            }
+            StatType::PlayerSeasonTotals => {
+                let rows = try!(fetch_with::<PlayerSeasonTotalsEndpoint>(transport, payload));
+                Ok(rows.into_iter().map(Stat::PlayerSeasonTotals).collect())
+            }
+            StatType::PlayerCareerTotals => {
+                let rows = try!(fetch_with::<PlayerCareerTotalsEndpoint>(transport, payload));
+                Ok(rows.into_iter().map(Stat::PlayerCareerTotals).collect())
+            }
+            StatType::PlayerPostSeasonTotals => {
+                let rows = try!(fetch_with::<PlayerPostSeasonTotalsEndpoint>(transport, payload));
+                Ok(rows.into_iter().map(Stat::PlayerPostSeasonTotals).collect())
+            }
+            StatType::PlayerPostSeasonCareerTotals => {
+                let rows = try!(fetch_with::<PlayerPostSeasonCareerTotalsEndpoint>(transport,
+                                                                                   payload));
+                Ok(rows.into_iter().map(Stat::PlayerPostSeasonCareerTotals).collect())
+            }
+            StatType::PlayerInfo => {
+                let rows = try!(fetch_with::<CommonPlayerInfoEndpoint>(transport, payload));
+                Ok(rows.into_iter().map(Stat::PlayerInfo).collect())
+            }
+            StatType::TeamGameLog => {
+                let rows = try!(fetch_with::<TeamGameLogEndpoint>(transport, payload));
+                Ok(rows.into_iter().map(Stat::TeamGameLog).collect())
+            }
+            StatType::LeagueGameFinder => {
+                let rows = try!(fetch_with::<LeagueGameFinderEndpoint>(transport, payload));
+                Ok(rows.into_iter().map(Stat::LeagueGameFinder).collect())
+            }
         }
-
-
     }
 }
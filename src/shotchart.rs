@@ -0,0 +1,167 @@
+use std::collections::BTreeMap;
+use stats::Shot;
+
+
+// Court geometry in feet, measured from the center of the hoop.
+static RESTRICTED_AREA_RADIUS: f64 = 4.0;
+static PAINT_HALF_WIDTH: f64 = 8.0;
+static FREE_THROW_LINE: f64 = 13.75;
+static THREE_POINT_RADIUS: f64 = 23.75;
+static CORNER_THREE_DISTANCE: f64 = 22.0;
+static CORNER_THREE_DEPTH: f64 = 8.75;
+static HALF_COURT_LINE: f64 = 41.75;
+
+/// Converts a `LOC_X` or `LOC_Y` value, in tenths of a foot, to feet.
+pub fn to_feet(tenths: i64) -> f64 {
+    tenths as f64 / 10.0
+}
+
+
+/// The basic shot zones stats.nba.com reports in `SHOT_ZONE_BASIC`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Zone {
+    RestrictedArea,
+    Paint,
+    MidRange,
+    LeftCorner3,
+    RightCorner3,
+    AboveTheBreak3,
+    Backcourt,
+}
+
+impl Zone {
+    /// The zone's name as it appears in `SHOT_ZONE_BASIC`.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Zone::RestrictedArea => "Restricted Area",
+            Zone::Paint => "In The Paint (Non-RA)",
+            Zone::MidRange => "Mid-Range",
+            Zone::LeftCorner3 => "Left Corner 3",
+            Zone::RightCorner3 => "Right Corner 3",
+            Zone::AboveTheBreak3 => "Above the Break 3",
+            Zone::Backcourt => "Backcourt",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Zone> {
+        match name {
+            "Restricted Area" => Some(Zone::RestrictedArea),
+            "In The Paint (Non-RA)" => Some(Zone::Paint),
+            "Mid-Range" => Some(Zone::MidRange),
+            "Left Corner 3" => Some(Zone::LeftCorner3),
+            "Right Corner 3" => Some(Zone::RightCorner3),
+            "Above the Break 3" => Some(Zone::AboveTheBreak3),
+            "Backcourt" => Some(Zone::Backcourt),
+            _ => None,
+        }
+    }
+
+    /// Buckets a location, in feet from the hoop, into a zone.
+    ///
+    /// stats.nba.com puts the right side of the court (as the shooter faces the hoop)
+    /// at negative x.
+    pub fn from_location(x: f64, y: f64) -> Zone {
+        let distance = (x * x + y * y).sqrt();
+        if y > HALF_COURT_LINE {
+            Zone::Backcourt
+        } else if y <= CORNER_THREE_DEPTH && x.abs() >= CORNER_THREE_DISTANCE {
+            if x < 0.0 {
+                Zone::RightCorner3
+            } else {
+                Zone::LeftCorner3
+            }
+        } else if y > CORNER_THREE_DEPTH && distance >= THREE_POINT_RADIUS {
+            Zone::AboveTheBreak3
+        } else if distance <= RESTRICTED_AREA_RADIUS {
+            Zone::RestrictedArea
+        } else if x.abs() <= PAINT_HALF_WIDTH && y <= FREE_THROW_LINE {
+            Zone::Paint
+        } else {
+            Zone::MidRange
+        }
+    }
+
+    pub fn is_three(&self) -> bool {
+        match *self {
+            Zone::LeftCorner3 | Zone::RightCorner3 | Zone::AboveTheBreak3 | Zone::Backcourt => true,
+            _ => false,
+        }
+    }
+}
+
+
+impl Shot {
+    pub fn x_feet(&self) -> Option<f64> {
+        self.loc_x.map(to_feet)
+    }
+
+    pub fn y_feet(&self) -> Option<f64> {
+        self.loc_y.map(to_feet)
+    }
+
+    /// Distance from the hoop computed from the coordinates. Unlike `shot_distance`, this
+    /// isn't truncated to whole feet.
+    pub fn distance_feet(&self) -> Option<f64> {
+        match (self.x_feet(), self.y_feet()) {
+            (Some(x), Some(y)) => Some((x * x + y * y).sqrt()),
+            _ => None,
+        }
+    }
+
+    pub fn made(&self) -> bool {
+        self.shot_made_flag == Some(true)
+    }
+
+    /// The shot's zone, as reported, or worked out from its coordinates when it isn't.
+    pub fn zone(&self) -> Option<Zone> {
+        let reported = self.shot_zone_basic.as_ref().and_then(|x| Zone::from_name(x));
+        match (reported, self.x_feet(), self.y_feet()) {
+            (Some(zone), _, _) => Some(zone),
+            (None, Some(x), Some(y)) => Some(Zone::from_location(x, y)),
+            _ => None,
+        }
+    }
+}
+
+
+/// Makes and attempts within a single zone.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ZoneSummary {
+    pub zone: Zone,
+    pub attempts: u32,
+    pub made: u32,
+}
+
+impl ZoneSummary {
+    pub fn fg_pct(&self) -> Option<f64> {
+        if self.attempts == 0 {
+            None
+        } else {
+            Some(self.made as f64 / self.attempts as f64)
+        }
+    }
+}
+
+/// Tallies shots by zone, in `Zone` order. Zones without attempts are left out, as are
+/// shots with neither a reported zone nor coordinates.
+pub fn by_zone(shots: &[Shot]) -> Vec<ZoneSummary> {
+    let mut tally: BTreeMap<Zone, (u32, u32)> = BTreeMap::new();
+    for shot in shots {
+        if let Some(zone) = shot.zone() {
+            let entry = tally.entry(zone).or_insert((0, 0));
+            entry.0 += 1;
+            if shot.made() {
+                entry.1 += 1;
+            }
+        }
+    }
+    tally.into_iter()
+         .map(|(zone, (attempts, made))| {
+             ZoneSummary {
+                 zone: zone,
+                 attempts: attempts,
+                 made: made,
+             }
+         })
+         .collect()
+}
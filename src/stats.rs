@@ -1,89 +1,907 @@
+use std::collections::BTreeMap;
+use teams;
+use teams::Team;
+use builders::{PlayerQueryBuilder, PlayByPlayQueryBuilder};
+use clock::GameClock;
+use description::Description;
+use decode::FromValue;
+use serde_json::Value;
+use endpoint::{fetch_with, PlayerGameLogEndpoint};
+use err::NBAError;
+use params::{GameId, Season, SeasonType};
+use queries::Query;
+use transport::{Transport, HyperTransport};
+
 pub enum StatType {
     PlayByPlay,
     GameHeader,
     EastConfStandings,
     WestConfStandings,
     TeamRoster,
+    BoxScoreTraditionalPlayers,
+    BoxScoreTraditionalTeams,
+    BoxScoreAdvancedPlayers,
+    BoxScoreAdvancedTeams,
+    BoxScoreFourFactorsPlayers,
+    BoxScoreFourFactorsTeams,
+    ShotChart,
+    ShotChartLeagueAverages,
+    PlayerGameLog,
+    PlayerSeasonTotals,
+    PlayerCareerTotals,
+    PlayerPostSeasonTotals,
+    PlayerPostSeasonCareerTotals,
+    PlayerInfo,
+    TeamGameLog,
+    LeagueGameFinder,
+    LeagueLeaders,
+    LeagueDashPlayerStats,
+    LeagueDashTeamStats,
+    LineScore,
+}
+
+
+/// A single play-by-play event.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayByPlayEvent {
+    pub game_id: Option<String>,
+    pub eventnum: Option<u64>,
+    pub eventmsgtype: Option<u64>,
+    pub eventmsgactiontype: Option<u64>,
+    pub period: Option<u64>,
+    pub wctimestring: Option<String>,
+    pub pctimestring: Option<GameClock>,
+    pub homedescription: Option<String>,
+    pub neutraldescription: Option<String>,
+    pub visitordescription: Option<String>,
+    /// The score after this event. Rows where nobody scored carry the last known score.
+    pub score: Option<Score>,
+    /// Home points minus away points, 0 when tied. Carried like `score`.
+    pub scoremargin: Option<i64>,
+    pub player1: Option<EventPerson>,
+    pub player2: Option<EventPerson>,
+    pub player3: Option<EventPerson>,
+    /// What could be read out of the descriptions.
+    pub details: Description,
+}
+
+/// A game's score, read from play-by-play `SCORE` values like "102 - 98", which list the
+/// away team first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Score {
+    pub home: u64,
+    pub away: u64,
+}
+
+impl Score {
+    pub fn parse(score: &str) -> Option<Score> {
+        let mut sides = score.split('-').map(|x| x.trim().parse::<u64>());
+        match (sides.next(), sides.next(), sides.next()) {
+            (Some(Ok(away)), Some(Ok(home)), None) => {
+                Some(Score {
+                    home: home,
+                    away: away,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Home points minus away points.
+    pub fn margin(&self) -> i64 {
+        self.home as i64 - self.away as i64
+    }
+}
+
+impl FromValue for Score {
+    fn from_value(value: &Value) -> Option<Score> {
+        value.as_string().and_then(Score::parse)
+    }
+    fn expected() -> &'static str {
+        "a score like \"102 - 98\""
+    }
+}
+
+/// A player or team taking part in a play-by-play event, from the `PERSONnTYPE` and
+/// `PLAYERn_*` columns.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventPerson {
+    pub person_type: Option<u64>,
+    /// A player id, or a team id for team events like timeouts and team rebounds.
+    pub id: String,
+    pub name: Option<String>,
+    pub team_id: Option<String>,
+    pub team_abbreviation: Option<String>,
 }
 
+impl EventPerson {
+    pub fn is_team(&self) -> bool {
+        self.person_type == Some(2) || self.person_type == Some(3)
+    }
+
+    pub fn is_home(&self) -> bool {
+        self.person_type == Some(2) || self.person_type == Some(4)
+    }
+
+    pub fn is_visitor(&self) -> bool {
+        self.person_type == Some(3) || self.person_type == Some(5)
+    }
+}
+
+/// One game on the scoreboard.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameHeader {
+    pub gamedate_est: Option<String>,
+    pub game_sequence: Option<u64>,
+    pub game_id: Option<String>,
+    pub game_status_id: Option<u64>,
+    pub game_status_text: Option<String>,
+    pub gamecode: Option<String>,
+    pub home_team_id: Option<String>,
+    pub visitor_team_id: Option<String>,
+    pub season: Option<u64>,
+    pub live_period: Option<u64>,
+    pub live_pc_time: Option<String>,
+    pub natl_tv_broadcaster_abbreviation: Option<String>,
+    pub live_period_time_bcast: Option<String>,
+    pub wh_status: Option<u64>,
+}
+
+/// A team's conference standing on a given day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StandingsRow {
+    pub team_id: Option<String>,
+    pub league_id: Option<String>,
+    pub season_id: Option<String>,
+    pub standings_date: Option<String>,
+    pub conference: Option<String>,
+    pub team: Option<String>,
+    pub g: Option<u64>,
+    pub wins: Option<u64>,
+    pub losses: Option<u64>,
+    pub w_pct: Option<f64>,
+    pub home_record: Option<String>,
+    pub road_record: Option<String>,
+}
+
+/// A team's points by period and shooting for one game on the scoreboard.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineScore {
+    pub game_date_est: Option<String>,
+    pub game_sequence: Option<u64>,
+    pub game_id: Option<String>,
+    pub team_id: Option<String>,
+    pub team_abbreviation: Option<String>,
+    pub team_city_name: Option<String>,
+    pub team_wins_losses: Option<String>,
+    pub pts_qtr1: Option<u64>,
+    pub pts_qtr2: Option<u64>,
+    pub pts_qtr3: Option<u64>,
+    pub pts_qtr4: Option<u64>,
+    pub pts_ot1: Option<u64>,
+    pub pts_ot2: Option<u64>,
+    pub pts_ot3: Option<u64>,
+    pub pts_ot4: Option<u64>,
+    pub pts_ot5: Option<u64>,
+    pub pts_ot6: Option<u64>,
+    pub pts_ot7: Option<u64>,
+    pub pts_ot8: Option<u64>,
+    pub pts_ot9: Option<u64>,
+    pub pts_ot10: Option<u64>,
+    pub pts: Option<u64>,
+    pub fg_pct: Option<f64>,
+    pub ft_pct: Option<f64>,
+    pub fg3_pct: Option<f64>,
+    pub ast: Option<u64>,
+    pub reb: Option<u64>,
+    pub tov: Option<u64>,
+}
+
+/// The season series between a game's two teams, as of that game.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeriesStanding {
+    pub game_id: Option<String>,
+    pub home_team_id: Option<String>,
+    pub visitor_team_id: Option<String>,
+    pub game_date_est: Option<String>,
+    pub home_team_wins: Option<u64>,
+    pub home_team_losses: Option<u64>,
+    pub series_leader: Option<String>,
+}
+
+/// The previous meeting between a game's two teams.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LastMeeting {
+    pub game_id: Option<String>,
+    pub last_game_id: Option<String>,
+    pub last_game_date_est: Option<String>,
+    pub last_game_home_team_id: Option<String>,
+    pub last_game_home_team_city: Option<String>,
+    pub last_game_home_team_name: Option<String>,
+    pub last_game_home_team_abbreviation: Option<String>,
+    pub last_game_home_team_points: Option<u64>,
+    pub last_game_visitor_team_id: Option<String>,
+    pub last_game_visitor_team_city: Option<String>,
+    pub last_game_visitor_team_name: Option<String>,
+    pub last_game_visitor_team_abbreviation: Option<String>,
+    pub last_game_visitor_team_points: Option<u64>,
+}
 
-#[derive(Debug)]
+/// Whether a game has player tracking data.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Availability {
+    pub game_id: Option<String>,
+    pub pt_available: Option<bool>,
+}
+
+/// A player on a team's roster.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RosterEntry {
+    pub team_id: Option<String>,
+    pub season: Option<String>,
+    pub league_id: Option<String>,
+    pub player: Option<String>,
+    pub num: Option<String>,
+    pub position: Option<String>,
+    pub height: Option<String>,
+    pub weight: Option<String>,
+    pub birth_date: Option<String>,
+    pub age: Option<u64>,
+    pub exp: Option<String>,
+    pub school: Option<String>,
+    pub player_id: Option<String>,
+}
+
+/// A player's traditional box score line.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoxScoreTraditionalPlayer {
+    pub game_id: Option<String>,
+    pub team_id: Option<String>,
+    pub team_abbreviation: Option<String>,
+    pub team_city: Option<String>,
+    pub player_id: Option<String>,
+    pub player_name: Option<String>,
+    pub start_position: Option<String>,
+    pub comment: Option<String>,
+    pub min: Option<String>,
+    pub fgm: Option<u64>,
+    pub fga: Option<u64>,
+    pub fg_pct: Option<f64>,
+    pub fg3m: Option<u64>,
+    pub fg3a: Option<u64>,
+    pub fg3_pct: Option<f64>,
+    pub ftm: Option<u64>,
+    pub fta: Option<u64>,
+    pub ft_pct: Option<f64>,
+    pub oreb: Option<u64>,
+    pub dreb: Option<u64>,
+    pub reb: Option<u64>,
+    pub ast: Option<u64>,
+    pub stl: Option<u64>,
+    pub blk: Option<u64>,
+    pub tov: Option<u64>,
+    pub pf: Option<u64>,
+    pub pts: Option<u64>,
+    pub plus_minus: Option<i64>,
+}
+
+/// A team's traditional box score totals.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoxScoreTraditionalTeam {
+    pub game_id: Option<String>,
+    pub team_id: Option<String>,
+    pub team_name: Option<String>,
+    pub team_abbreviation: Option<String>,
+    pub team_city: Option<String>,
+    pub min: Option<String>,
+    pub fgm: Option<u64>,
+    pub fga: Option<u64>,
+    pub fg_pct: Option<f64>,
+    pub fg3m: Option<u64>,
+    pub fg3a: Option<u64>,
+    pub fg3_pct: Option<f64>,
+    pub ftm: Option<u64>,
+    pub fta: Option<u64>,
+    pub ft_pct: Option<f64>,
+    pub oreb: Option<u64>,
+    pub dreb: Option<u64>,
+    pub reb: Option<u64>,
+    pub ast: Option<u64>,
+    pub stl: Option<u64>,
+    pub blk: Option<u64>,
+    pub tov: Option<u64>,
+    pub pf: Option<u64>,
+    pub pts: Option<u64>,
+    pub plus_minus: Option<i64>,
+}
+
+/// A player's advanced box score line.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoxScoreAdvancedPlayer {
+    pub game_id: Option<String>,
+    pub team_id: Option<String>,
+    pub team_abbreviation: Option<String>,
+    pub team_city: Option<String>,
+    pub player_id: Option<String>,
+    pub player_name: Option<String>,
+    pub start_position: Option<String>,
+    pub comment: Option<String>,
+    pub min: Option<String>,
+    pub off_rating: Option<f64>,
+    pub def_rating: Option<f64>,
+    pub net_rating: Option<f64>,
+    pub ast_pct: Option<f64>,
+    pub ast_tov: Option<f64>,
+    pub ast_ratio: Option<f64>,
+    pub oreb_pct: Option<f64>,
+    pub dreb_pct: Option<f64>,
+    pub reb_pct: Option<f64>,
+    pub tm_tov_pct: Option<f64>,
+    pub efg_pct: Option<f64>,
+    pub ts_pct: Option<f64>,
+    pub usg_pct: Option<f64>,
+    pub pace: Option<f64>,
+    pub pie: Option<f64>,
+}
+
+/// A team's advanced box score line.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoxScoreAdvancedTeam {
+    pub game_id: Option<String>,
+    pub team_id: Option<String>,
+    pub team_name: Option<String>,
+    pub team_abbreviation: Option<String>,
+    pub team_city: Option<String>,
+    pub min: Option<String>,
+    pub off_rating: Option<f64>,
+    pub def_rating: Option<f64>,
+    pub net_rating: Option<f64>,
+    pub ast_pct: Option<f64>,
+    pub ast_tov: Option<f64>,
+    pub ast_ratio: Option<f64>,
+    pub oreb_pct: Option<f64>,
+    pub dreb_pct: Option<f64>,
+    pub reb_pct: Option<f64>,
+    pub tm_tov_pct: Option<f64>,
+    pub efg_pct: Option<f64>,
+    pub ts_pct: Option<f64>,
+    pub usg_pct: Option<f64>,
+    pub pace: Option<f64>,
+    pub pie: Option<f64>,
+}
+
+/// A player's four factors line, with the opponent's factors while on the floor.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoxScoreFourFactorsPlayer {
+    pub game_id: Option<String>,
+    pub team_id: Option<String>,
+    pub team_abbreviation: Option<String>,
+    pub team_city: Option<String>,
+    pub player_id: Option<String>,
+    pub player_name: Option<String>,
+    pub start_position: Option<String>,
+    pub comment: Option<String>,
+    pub min: Option<String>,
+    pub efg_pct: Option<f64>,
+    pub fta_rate: Option<f64>,
+    pub tm_tov_pct: Option<f64>,
+    pub oreb_pct: Option<f64>,
+    pub opp_efg_pct: Option<f64>,
+    pub opp_fta_rate: Option<f64>,
+    pub opp_tov_pct: Option<f64>,
+    pub opp_oreb_pct: Option<f64>,
+}
+
+/// A team's four factors line.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoxScoreFourFactorsTeam {
+    pub game_id: Option<String>,
+    pub team_id: Option<String>,
+    pub team_name: Option<String>,
+    pub team_abbreviation: Option<String>,
+    pub team_city: Option<String>,
+    pub min: Option<String>,
+    pub efg_pct: Option<f64>,
+    pub fta_rate: Option<f64>,
+    pub tm_tov_pct: Option<f64>,
+    pub oreb_pct: Option<f64>,
+    pub opp_efg_pct: Option<f64>,
+    pub opp_fta_rate: Option<f64>,
+    pub opp_tov_pct: Option<f64>,
+    pub opp_oreb_pct: Option<f64>,
+}
+
+/// A single field goal attempt from the shot chart.
+///
+/// `loc_x` and `loc_y` are in tenths of a foot, with the hoop at the origin; see
+/// `shotchart` for conversions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Shot {
+    pub game_id: Option<String>,
+    pub game_event_id: Option<u64>,
+    pub player_id: Option<String>,
+    pub player_name: Option<String>,
+    pub team_id: Option<String>,
+    pub team_name: Option<String>,
+    pub period: Option<u64>,
+    pub minutes_remaining: Option<u64>,
+    pub seconds_remaining: Option<u64>,
+    pub event_type: Option<String>,
+    pub action_type: Option<String>,
+    pub shot_type: Option<String>,
+    pub shot_zone_basic: Option<String>,
+    pub shot_zone_area: Option<String>,
+    pub shot_zone_range: Option<String>,
+    pub shot_distance: Option<u64>,
+    pub loc_x: Option<i64>,
+    pub loc_y: Option<i64>,
+    pub shot_attempted_flag: Option<bool>,
+    pub shot_made_flag: Option<bool>,
+}
+
+/// League-wide shooting in one zone, returned alongside a shot chart.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShotZoneAverage {
+    pub shot_zone_basic: Option<String>,
+    pub shot_zone_area: Option<String>,
+    pub shot_zone_range: Option<String>,
+    pub fga: Option<u64>,
+    pub fgm: Option<u64>,
+    pub fg_pct: Option<f64>,
+}
+
+/// One game from a player's game log.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayerGameLogEntry {
+    pub season_id: Option<String>,
+    pub player_id: Option<String>,
+    pub game_id: Option<String>,
+    pub game_date: Option<String>,
+    pub matchup: Option<String>,
+    pub wl: Option<String>,
+    pub min: Option<u64>,
+    pub fgm: Option<u64>,
+    pub fga: Option<u64>,
+    pub fg_pct: Option<f64>,
+    pub fg3m: Option<u64>,
+    pub fg3a: Option<u64>,
+    pub fg3_pct: Option<f64>,
+    pub ftm: Option<u64>,
+    pub fta: Option<u64>,
+    pub ft_pct: Option<f64>,
+    pub oreb: Option<u64>,
+    pub dreb: Option<u64>,
+    pub reb: Option<u64>,
+    pub ast: Option<u64>,
+    pub stl: Option<u64>,
+    pub blk: Option<u64>,
+    pub tov: Option<u64>,
+    pub pf: Option<u64>,
+    pub pts: Option<u64>,
+    pub plus_minus: Option<i64>,
+    pub video_available: Option<bool>,
+}
+
+/// A player's numbers for one season with one team. Per-game and per-36 modes make the
+/// counting stats fractional, so they're all read as floats.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayerSeasonStats {
+    pub player_id: Option<String>,
+    pub season_id: Option<String>,
+    pub league_id: Option<String>,
+    pub team_id: Option<String>,
+    pub team_abbreviation: Option<String>,
+    pub player_age: Option<f64>,
+    pub gp: Option<u64>,
+    pub gs: Option<u64>,
+    pub min: Option<f64>,
+    pub fgm: Option<f64>,
+    pub fga: Option<f64>,
+    pub fg_pct: Option<f64>,
+    pub fg3m: Option<f64>,
+    pub fg3a: Option<f64>,
+    pub fg3_pct: Option<f64>,
+    pub ftm: Option<f64>,
+    pub fta: Option<f64>,
+    pub ft_pct: Option<f64>,
+    pub oreb: Option<f64>,
+    pub dreb: Option<f64>,
+    pub reb: Option<f64>,
+    pub ast: Option<f64>,
+    pub stl: Option<f64>,
+    pub blk: Option<f64>,
+    pub tov: Option<f64>,
+    pub pf: Option<f64>,
+    pub pts: Option<f64>,
+}
+
+/// A player's career numbers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayerCareerTotals {
+    pub player_id: Option<String>,
+    pub league_id: Option<String>,
+    pub team_id: Option<String>,
+    pub gp: Option<u64>,
+    pub gs: Option<u64>,
+    pub min: Option<f64>,
+    pub fgm: Option<f64>,
+    pub fga: Option<f64>,
+    pub fg_pct: Option<f64>,
+    pub fg3m: Option<f64>,
+    pub fg3a: Option<f64>,
+    pub fg3_pct: Option<f64>,
+    pub ftm: Option<f64>,
+    pub fta: Option<f64>,
+    pub ft_pct: Option<f64>,
+    pub oreb: Option<f64>,
+    pub dreb: Option<f64>,
+    pub reb: Option<f64>,
+    pub ast: Option<f64>,
+    pub stl: Option<f64>,
+    pub blk: Option<f64>,
+    pub tov: Option<f64>,
+    pub pf: Option<f64>,
+    pub pts: Option<f64>,
+}
+
+/// Biographical details for a player.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayerInfo {
+    pub person_id: Option<String>,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub display_first_last: Option<String>,
+    pub birthdate: Option<String>,
+    pub school: Option<String>,
+    pub country: Option<String>,
+    pub height: Option<String>,
+    pub weight: Option<String>,
+    pub season_exp: Option<u64>,
+    pub jersey: Option<String>,
+    pub position: Option<String>,
+    pub rosterstatus: Option<String>,
+    pub team_id: Option<String>,
+    pub team_name: Option<String>,
+    pub team_abbreviation: Option<String>,
+    pub team_city: Option<String>,
+    pub from_year: Option<u64>,
+    pub to_year: Option<u64>,
+    pub draft_year: Option<String>,
+    pub draft_round: Option<String>,
+    pub draft_number: Option<String>,
+}
+
+/// One game from a team's game log, with the team's record after it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TeamGameLogEntry {
+    pub team_id: Option<String>,
+    pub game_id: Option<String>,
+    pub game_date: Option<String>,
+    pub matchup: Option<String>,
+    pub wl: Option<String>,
+    pub wins: Option<u64>,
+    pub losses: Option<u64>,
+    pub w_pct: Option<f64>,
+    pub min: Option<u64>,
+    pub fgm: Option<u64>,
+    pub fga: Option<u64>,
+    pub fg_pct: Option<f64>,
+    pub fg3m: Option<u64>,
+    pub fg3a: Option<u64>,
+    pub fg3_pct: Option<f64>,
+    pub ftm: Option<u64>,
+    pub fta: Option<u64>,
+    pub ft_pct: Option<f64>,
+    pub oreb: Option<u64>,
+    pub dreb: Option<u64>,
+    pub reb: Option<u64>,
+    pub ast: Option<u64>,
+    pub stl: Option<u64>,
+    pub blk: Option<u64>,
+    pub tov: Option<u64>,
+    pub pf: Option<u64>,
+    pub pts: Option<u64>,
+}
+
+/// One team's side of a game matched by the league game finder.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameFinderResult {
+    pub season_id: Option<String>,
+    pub team_id: Option<String>,
+    pub team_abbreviation: Option<String>,
+    pub team_name: Option<String>,
+    pub game_id: Option<String>,
+    pub game_date: Option<String>,
+    pub matchup: Option<String>,
+    pub wl: Option<String>,
+    pub min: Option<u64>,
+    pub pts: Option<u64>,
+    pub fgm: Option<u64>,
+    pub fga: Option<u64>,
+    pub fg_pct: Option<f64>,
+    pub fg3m: Option<u64>,
+    pub fg3a: Option<u64>,
+    pub fg3_pct: Option<f64>,
+    pub ftm: Option<u64>,
+    pub fta: Option<u64>,
+    pub ft_pct: Option<f64>,
+    pub oreb: Option<u64>,
+    pub dreb: Option<u64>,
+    pub reb: Option<u64>,
+    pub ast: Option<u64>,
+    pub stl: Option<u64>,
+    pub blk: Option<u64>,
+    pub tov: Option<u64>,
+    pub pf: Option<u64>,
+    pub plus_minus: Option<i64>,
+}
+
+/// A player's line in the league leaders, ranked by the requested stat category.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LeagueLeader {
+    pub player_id: Option<String>,
+    pub rank: Option<u64>,
+    pub player: Option<String>,
+    pub team: Option<String>,
+    pub gp: Option<u64>,
+    pub min: Option<f64>,
+    pub fgm: Option<f64>,
+    pub fga: Option<f64>,
+    pub fg_pct: Option<f64>,
+    pub fg3m: Option<f64>,
+    pub fg3a: Option<f64>,
+    pub fg3_pct: Option<f64>,
+    pub ftm: Option<f64>,
+    pub fta: Option<f64>,
+    pub ft_pct: Option<f64>,
+    pub oreb: Option<f64>,
+    pub dreb: Option<f64>,
+    pub reb: Option<f64>,
+    pub ast: Option<f64>,
+    pub stl: Option<f64>,
+    pub blk: Option<f64>,
+    pub tov: Option<f64>,
+    pub pts: Option<f64>,
+    pub eff: Option<f64>,
+}
+
+/// A player's line from the league dashboard. The columns depend on the measure type, so
+/// everything past the identifying columns and record is kept by column name in `stats`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LeagueDashPlayerRow {
+    pub player_id: Option<String>,
+    pub player_name: Option<String>,
+    pub team_id: Option<String>,
+    pub team_abbreviation: Option<String>,
+    pub age: Option<f64>,
+    pub gp: Option<u64>,
+    pub wins: Option<u64>,
+    pub losses: Option<u64>,
+    pub w_pct: Option<f64>,
+    pub min: Option<f64>,
+    pub stats: BTreeMap<String, f64>,
+}
+
+/// A team's line from the league dashboard, laid out like `LeagueDashPlayerRow`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LeagueDashTeamRow {
+    pub team_id: Option<String>,
+    pub team_name: Option<String>,
+    pub gp: Option<u64>,
+    pub wins: Option<u64>,
+    pub losses: Option<u64>,
+    pub w_pct: Option<f64>,
+    pub min: Option<f64>,
+    pub stats: BTreeMap<String, f64>,
+}
+
+
+/// Rows from any endpoint. Kept for callers of `get_data`; prefer `endpoint::fetch`.
+///
+/// Since 0.2.0 each variant wraps its endpoint's row struct. Matches like
+/// `Stat::PlayByPlay { eventnum, .. }` become `Stat::PlayByPlay(ref event)` and read
+/// `event.eventnum`.
+#[derive(Debug, Clone, PartialEq)]
 pub enum Stat {
-    PlayByPlay {
-        game_id: Option<i64>,
-        eventnum: Option<u64>,
-        eventmsgtype: Option<u64>,
-        eventmsgactiontype: Option<u64>,
-        period: Option<u64>,
-        wctimestring: Option<String>,
-        pctimestring: Option<String>,
-        homedescription: Option<String>,
-        neutraldescription: Option<String>,
-        visitordescription: Option<String>,
-        score: Option<u64>,
-        scoremargin: Option<u64>,
-    },
-
-    GameHeader {
-        gamedate_est: Option<String>,
-        game_sequence: Option<u64>,
-        game_id: Option<String>,
-        game_status_id: Option<u64>,
-        game_status_text: Option<String>,
-        gamecode: Option<String>,
-        home_team_id: Option<String>,
-        visitor_team_id: Option<String>,
-        season: Option<u64>,
-        live_period: Option<u64>,
-        live_pc_time: Option<u64>,
-        natl_tv_broadcaster_abbreviation: Option<String>,
-        live_period_time_bcast: Option<String>,
-        wh_status: Option<u64>,
-    },
-
-    EastConfStandings {
-        team_id: Option<String>,
-        league_id: Option<String>,
-        season_id: Option<String>,
-        standings_date: Option<String>,
-        conference: Option<String>,
-        team: Option<String>,
-        g: Option<u64>,
-        wins: Option<u64>,
-        losses: Option<u64>,
-        w_pct: Option<f64>,
-        home_record: Option<String>,
-        road_record: Option<String>,
-    },
-
-    WestConfStandings {
-        team_id: Option<String>,
-        league_id: Option<String>,
-        season_id: Option<String>,
-        standings_date: Option<String>,
-        conference: Option<String>,
-        team: Option<String>,
-        g: Option<u64>,
-        wins: Option<u64>,
-        losses: Option<u64>,
-        w_pct: Option<f64>,
-        home_record: Option<String>,
-        road_record: Option<String>,
-    },
-
-    TeamRoster {
-        team_id: Option<String>,
-        season: Option<String>,
-        league_id: Option<String>,
-        player: Option<String>,
-        num: Option<String>,
-        position: Option<String>,
-        height: Option<String>,
-        weight: Option<String>,
-        birth_date: Option<String>,
-        age: Option<u64>,
-        exp: Option<String>,
-        school: Option<String>,
-        player_id: Option<String>,
-    },
+    PlayByPlay(PlayByPlayEvent),
+    GameHeader(GameHeader),
+    EastConfStandings(StandingsRow),
+    WestConfStandings(StandingsRow),
+    TeamRoster(RosterEntry),
+    BoxScoreTraditionalPlayer(BoxScoreTraditionalPlayer),
+    BoxScoreTraditionalTeam(BoxScoreTraditionalTeam),
+    BoxScoreAdvancedPlayer(BoxScoreAdvancedPlayer),
+    BoxScoreAdvancedTeam(BoxScoreAdvancedTeam),
+    BoxScoreFourFactorsPlayer(BoxScoreFourFactorsPlayer),
+    BoxScoreFourFactorsTeam(BoxScoreFourFactorsTeam),
+    Shot(Shot),
+    ShotZoneAverage(ShotZoneAverage),
+    PlayerGameLog(PlayerGameLogEntry),
+    PlayerSeasonTotals(PlayerSeasonStats),
+    PlayerCareerTotals(PlayerCareerTotals),
+    PlayerPostSeasonTotals(PlayerSeasonStats),
+    PlayerPostSeasonCareerTotals(PlayerCareerTotals),
+    PlayerInfo(PlayerInfo),
+    TeamGameLog(TeamGameLogEntry),
+    LeagueGameFinder(GameFinderResult),
+    LeagueLeaders(LeagueLeader),
+    LeagueDashPlayerStats(LeagueDashPlayerRow),
+    LeagueDashTeamStats(LeagueDashTeamRow),
+    LineScore(LineScore),
+}
+
+impl Stat {
+    /// Home team of a `GameHeader` row.
+    pub fn home_team(&self) -> Option<&'static Team> {
+        match *self {
+            Stat::GameHeader(ref game) => game.home_team(),
+            _ => None,
+        }
+    }
+
+    /// Visiting team of a `GameHeader` row.
+    pub fn visitor_team(&self) -> Option<&'static Team> {
+        match *self {
+            Stat::GameHeader(ref game) => game.visitor_team(),
+            _ => None,
+        }
+    }
+
+    /// Team of a standings, roster or player info row.
+    pub fn team(&self) -> Option<&'static Team> {
+        match *self {
+            Stat::EastConfStandings(ref row) |
+            Stat::WestConfStandings(ref row) => row.team(),
+            Stat::TeamRoster(ref row) => row.team(),
+            Stat::PlayerInfo(ref row) => row.team(),
+            _ => None,
+        }
+    }
+}
+
+impl GameHeader {
+    pub fn home_team(&self) -> Option<&'static Team> {
+        self.home_team_id.as_ref().and_then(|x| teams::by_id(x))
+    }
+
+    pub fn visitor_team(&self) -> Option<&'static Team> {
+        self.visitor_team_id.as_ref().and_then(|x| teams::by_id(x))
+    }
+}
+
+impl LineScore {
+    pub fn team(&self) -> Option<&'static Team> {
+        self.team_id.as_ref().and_then(|x| teams::by_id(x))
+    }
+
+    /// Points scored in a period, counting overtimes on from 5.
+    pub fn period_points(&self, period: u64) -> Option<u64> {
+        match period {
+            1 => self.pts_qtr1,
+            2 => self.pts_qtr2,
+            3 => self.pts_qtr3,
+            4 => self.pts_qtr4,
+            5 => self.pts_ot1,
+            6 => self.pts_ot2,
+            7 => self.pts_ot3,
+            8 => self.pts_ot4,
+            9 => self.pts_ot5,
+            10 => self.pts_ot6,
+            11 => self.pts_ot7,
+            12 => self.pts_ot8,
+            13 => self.pts_ot9,
+            14 => self.pts_ot10,
+            _ => None,
+        }
+    }
+
+    /// Points in each of the first `periods` periods. Pair it with the game header's
+    /// `live_period`, since unplayed overtimes are reported as zero.
+    pub fn points_by_period(&self, periods: u64) -> Vec<Option<u64>> {
+        (1..periods + 1).map(|x| self.period_points(x)).collect()
+    }
+}
+
+impl StandingsRow {
+    pub fn team(&self) -> Option<&'static Team> {
+        self.team_id.as_ref().and_then(|x| teams::by_id(x))
+    }
+}
+
+impl RosterEntry {
+    pub fn team(&self) -> Option<&'static Team> {
+        self.team_id.as_ref().and_then(|x| teams::by_id(x))
+    }
+
+    /// Query for this player's game log over the roster's season.
+    pub fn game_log_query(&self, season_type: SeasonType) -> Result<Query, NBAError> {
+        let player_id = try!(self.player_id.as_ref().ok_or(NBAError::MissingField("PLAYER_ID")));
+        let season = try!(self.season.as_ref().ok_or(NBAError::MissingField("SEASON")));
+        // Rosters name the season by the year it starts in, e.g. "2015" for 2015-16.
+        let start_year = try!(season.parse::<u16>().map_err(|_| {
+            NBAError::InvalidQuery {
+                field: "season",
+                value: season.clone(),
+                expected: "a four digit year",
+            }
+        }));
+        Ok(PlayerQueryBuilder::new(player_id)
+               .season_type(season_type)
+               .game_log(try!(Season::new(start_year))))
+    }
+
+    /// Fetches this player's regular season game log.
+    pub fn game_log(&self) -> Result<Vec<PlayerGameLogEntry>, NBAError> {
+        self.game_log_with(&HyperTransport::new())
+    }
+
+    pub fn game_log_with(&self,
+                         transport: &Transport)
+                         -> Result<Vec<PlayerGameLogEntry>, NBAError> {
+        let query = try!(self.game_log_query(SeasonType::RegularSeason));
+        fetch_with::<PlayerGameLogEndpoint>(transport, query)
+    }
+}
+
+impl PlayerInfo {
+    pub fn team(&self) -> Option<&'static Team> {
+        self.team_id.as_ref().and_then(|x| teams::by_id(x))
+    }
+}
+
+impl LeagueDashPlayerRow {
+    /// Looks up a dashboard column, e.g. "PTS" or "NET_RATING".
+    pub fn stat(&self, column: &str) -> Option<f64> {
+        self.stats.get(column).cloned()
+    }
+
+    pub fn team(&self) -> Option<&'static Team> {
+        self.team_id.as_ref().and_then(|x| teams::by_id(x))
+    }
+}
+
+impl LeagueDashTeamRow {
+    /// Looks up a dashboard column, e.g. "PTS" or "NET_RATING".
+    pub fn stat(&self, column: &str) -> Option<f64> {
+        self.stats.get(column).cloned()
+    }
+
+    pub fn team(&self) -> Option<&'static Team> {
+        self.team_id.as_ref().and_then(|x| teams::by_id(x))
+    }
+}
+
+fn game_id(id: &Option<String>) -> Result<GameId, NBAError> {
+    let id = try!(id.as_ref().ok_or(NBAError::MissingField("GAME_ID")));
+    GameId::new(id)
+}
+
+impl TeamGameLogEntry {
+    pub fn team(&self) -> Option<&'static Team> {
+        self.team_id.as_ref().and_then(|x| teams::by_id(x))
+    }
+
+    pub fn game_id(&self) -> Result<GameId, NBAError> {
+        game_id(&self.game_id)
+    }
+
+    /// Query for the full play-by-play of this game.
+    pub fn play_by_play_query(&self) -> Result<Query, NBAError> {
+        Ok(PlayByPlayQueryBuilder::new(try!(self.game_id())).build())
+    }
+}
+
+impl GameFinderResult {
+    pub fn team(&self) -> Option<&'static Team> {
+        self.team_id.as_ref().and_then(|x| teams::by_id(x))
+    }
+
+    pub fn game_id(&self) -> Result<GameId, NBAError> {
+        game_id(&self.game_id)
+    }
+
+    /// Query for the full play-by-play of this game.
+    pub fn play_by_play_query(&self) -> Result<Query, NBAError> {
+        Ok(PlayByPlayQueryBuilder::new(try!(self.game_id())).build())
+    }
 }
@@ -0,0 +1,135 @@
+use std::collections::BTreeMap;
+use serde_json;
+use serde_json::Value;
+use err::NBAError;
+
+static TEAMS_JSON: &'static str = include_str!("teams.json");
+
+lazy_static! {
+    static ref TEAMS: Vec<Team> = load_teams(TEAMS_JSON)
+                                      .expect("bundled teams.json is malformed");
+}
+
+
+/// A franchise, as described in the bundled `teams.json`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Team {
+    pub abbr: String,
+    pub id: String,
+    pub city: String,
+    pub name: String,
+    pub code: String,
+    pub conference: String,
+    pub division: String,
+    pub display_abbr: String,
+    pub display_conference: String,
+    pub color: String,
+    pub colors: Vec<String>,
+}
+
+impl Team {
+    /// City and nickname, e.g. "Cleveland Cavaliers".
+    pub fn full_name(&self) -> String {
+        format!("{} {}", self.city, self.name)
+    }
+}
+
+
+fn field(team: &BTreeMap<String, Value>, key: &'static str) -> Result<String, NBAError> {
+    team.get(key)
+        .and_then(|x| x.as_string())
+        .map(|x| x.to_owned())
+        .ok_or(NBAError::MissingField(key))
+}
+
+fn load_teams(s: &str) -> Result<Vec<Team>, NBAError> {
+    let data: Value = try!(serde_json::from_str(s));
+    let data = try!(data.as_object().ok_or(NBAError::ObjectError));
+    let mut teams = Vec::new();
+    for team in data.values() {
+        let team = try!(team.as_object().ok_or(NBAError::ObjectError));
+        let colors = try!(team.get("colors")
+                              .and_then(|x| x.as_array())
+                              .ok_or(NBAError::MissingField("colors")));
+        teams.push(Team {
+            abbr: try!(field(team, "abbr")),
+            id: try!(field(team, "id")),
+            city: try!(field(team, "city")),
+            name: try!(field(team, "name")),
+            code: try!(field(team, "code")),
+            conference: try!(field(team, "conference")),
+            division: try!(field(team, "division")),
+            display_abbr: try!(field(team, "displayAbbr")),
+            display_conference: try!(field(team, "displayConference")),
+            color: try!(field(team, "color")),
+            colors: colors.iter().filter_map(|x| x.as_string()).map(|x| x.to_owned()).collect(),
+        });
+    }
+    teams.sort_by(|a, b| a.abbr.cmp(&b.abbr));
+    Ok(teams)
+}
+
+fn same(a: &str, b: &str) -> bool {
+    a.to_lowercase() == b.to_lowercase()
+}
+
+
+/// Every franchise, sorted by abbreviation.
+pub fn all() -> &'static [Team] {
+    &TEAMS
+}
+
+/// Looks a team up by abbreviation, e.g. "CLE".
+pub fn by_abbr(abbr: &str) -> Option<&'static Team> {
+    TEAMS.iter().find(|t| same(&t.abbr, abbr))
+}
+
+/// Looks a team up by its stats.nba.com id, e.g. "1610612739".
+pub fn by_id(id: &str) -> Option<&'static Team> {
+    TEAMS.iter().find(|t| t.id == id)
+}
+
+/// Looks a team up by nickname, e.g. "Cavaliers".
+pub fn by_name(name: &str) -> Option<&'static Team> {
+    TEAMS.iter().find(|t| same(&t.name, name))
+}
+
+/// Looks a team up by its lowercase code, e.g. "cavaliers".
+pub fn by_code(code: &str) -> Option<&'static Team> {
+    TEAMS.iter().find(|t| same(&t.code, code))
+}
+
+/// All teams playing out of a city. Los Angeles has two.
+pub fn by_city(city: &str) -> Vec<&'static Team> {
+    TEAMS.iter().filter(|t| same(&t.city, city)).collect()
+}
+
+/// All teams in a conference. Accepts "East" as well as "Eastern".
+pub fn by_conference(conference: &str) -> Vec<&'static Team> {
+    TEAMS.iter()
+         .filter(|t| {
+             same(&t.conference, conference) || same(&t.conference, &format!("{}ern", conference))
+         })
+         .collect()
+}
+
+/// All teams in a division, e.g. "Central".
+pub fn by_division(division: &str) -> Vec<&'static Team> {
+    TEAMS.iter().filter(|t| same(&t.division, division)).collect()
+}
+
+/// Resolves an id, abbreviation, nickname, code or (unambiguous) city to a team.
+pub fn find(s: &str) -> Option<&'static Team> {
+    by_id(s)
+        .or_else(|| by_abbr(s))
+        .or_else(|| by_name(s))
+        .or_else(|| by_code(s))
+        .or_else(|| {
+            let teams = by_city(s);
+            if teams.len() == 1 {
+                Some(teams[0])
+            } else {
+                None
+            }
+        })
+}
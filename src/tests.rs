@@ -6,63 +6,154 @@ mod tests {
     use scrape::*;
     use parse::*;
     use constants::*;
+    use transport::*;
+    use cassette;
+    use teams;
+    use params::*;
+    use builders::*;
+    use endpoint::*;
+    use shotchart;
+    use shotchart::Zone;
+    use scoreboard::Scoreboard;
+    use events::*;
+    use description;
+    use description::Description;
+    use clock;
+    use clock::{GameClock, WallClock};
+    use replay::*;
+    use boxscore::BoxScore;
+    use lineups;
+    use possessions;
+    use possessions::Pace;
+    use winprob;
+    use winprob::{Features, WinProbabilityModel};
+    use momentum;
+    use momentum::MomentumEvent;
+    use decode::*;
+    use cassette::ReplayTransport;
+    use err::NBAError;
+    use hyper::Url;
     use serde_json::Value;
     use serde_json;
+    fn fixture(name: &str) -> Box<Transport> {
+        let path = format!("{}/fixtures/{}", env!("CARGO_MANIFEST_DIR"), name);
+        cassette::from_env(path).unwrap()
+    }
+
+    /// The synthetic play-by-play fixture of game 0041400106: the first half, with most of the
+    /// 2nd period cut out by hand.
+    fn playbyplay_events() -> Vec<PlayByPlayEvent> {
+        let query = PlayByPlayQueryBuilder::new(GameId::new("0041400106").unwrap()).build();
+        fetch_with::<PlayByPlayEndpoint>(&*fixture("playbyplay.json"), query).unwrap()
+    }
+
+    /// Decodes hand-written play-by-play rows, each ending with `PERSON1TYPE` and
+    /// `PLAYER1_ID`.
+    fn events_from_rows(rows: &str) -> Vec<PlayByPlayEvent> {
+        let headers: Value = serde_json::from_str(r#"["GAME_ID", "EVENTNUM", "EVENTMSGTYPE",
+            "EVENTMSGACTIONTYPE", "PERIOD", "WCTIMESTRING", "PCTIMESTRING", "HOMEDESCRIPTION",
+            "NEUTRALDESCRIPTION", "VISITORDESCRIPTION", "SCORE", "SCOREMARGIN", "PERSON1TYPE",
+            "PLAYER1_ID"]"#)
+                                  .unwrap();
+        let rows: Value = serde_json::from_str(rows).unwrap();
+        decode_rows(headers.as_array().unwrap(), rows.as_array().unwrap()).unwrap()
+    }
+
     #[test]
     fn test_get_playbyplay() {
+        let transport = fixture("playbyplay.json");
         let payload = Query::PlayByPlayQuery {
             gameid: "0041400106".to_owned(),
             startperiod: "0".to_owned(),
             endperiod: "14".to_owned(),
         };
-        let stats = Stat::get_data(StatType::PlayByPlay, payload);
+        let stats = Stat::get_data_with(&*transport, StatType::PlayByPlay, payload).unwrap();
+        assert_eq!(stats.len(), 49);
+        match stats[2] {
+            Stat::PlayByPlay(ref event) => {
+                assert_eq!(event.eventnum, Some(3));
+                assert_eq!(event.eventmsgtype, Some(1));
+                assert_eq!(event.visitordescription.as_ref().map(|x| &x[..]),
+                           Some("Korver 25' 3PT Jump Shot (3 PTS) (Teague 1 AST)"));
+            }
+            _ => panic!("expected a PlayByPlay row"),
+        }
     }
 
     #[test]
     fn test_get_gameheader() {
+        let transport = fixture("scoreboard.json");
         let payload = Query::GameHeaderQuery {
             leagueid: "00".to_owned(),
             gamedate: "02/21/2015".to_owned(),
             dayoffset: "0".to_owned(),
         };
-        let stats = Stat::get_data(StatType::GameHeader, payload);
+        let stats = Stat::get_data_with(&*transport, StatType::GameHeader, payload).unwrap();
+        assert_eq!(stats.len(), 2);
+        match stats[1] {
+            Stat::GameHeader(ref game) => {
+                assert_eq!(game.game_id.as_ref().map(|x| &x[..]), Some("0021400830"));
+                assert_eq!(game.gamecode.as_ref().map(|x| &x[..]), Some("20150221/MEMATL"));
+            }
+            _ => panic!("expected a GameHeader row"),
+        }
     }
     #[test]
     fn test_get_eastconfstandings() {
+        let transport = fixture("scoreboard.json");
         let payload = Query::EastConfStandingsQuery {
             leagueid: "00".to_owned(),
             gamedate: "02/21/2015".to_owned(),
             dayoffset: "0".to_owned(),
         };
-        let stats = Stat::get_data(StatType::EastConfStandings, payload);
+        let stats = Stat::get_data_with(&*transport, StatType::EastConfStandings, payload)
+                        .unwrap();
+        assert_eq!(stats.len(), 3);
     }
 
     #[test]
     fn test_get_westconfstandings() {
+        let transport = fixture("scoreboard.json");
         let payload = Query::WestConfStandingsQuery {
             leagueid: "00".to_owned(),
             gamedate: "02/21/2015".to_owned(),
             dayoffset: "0".to_owned(),
         };
-        let stats = Stat::get_data(StatType::WestConfStandings, payload);
+        let stats = Stat::get_data_with(&*transport, StatType::WestConfStandings, payload)
+                        .unwrap();
+        assert_eq!(stats.len(), 3);
     }
 
     #[test]
     fn test_get_teamroster() {
+        let transport = fixture("commonteamroster.json");
         let payload = Query::TeamRosterQuery {
             teamid: "1610612739".to_owned(),
             season: "2015-16".to_owned(),
         };
-        let stats = Stat::get_data(StatType::TeamRoster, payload);
+        let stats = Stat::get_data_with(&*transport, StatType::TeamRoster, payload).unwrap();
+        assert_eq!(stats.len(), 5);
+        match stats[1] {
+            Stat::TeamRoster(ref entry) => {
+                assert_eq!(entry.player.as_ref().map(|x| &x[..]), Some("LeBron James"));
+                assert_eq!(entry.school.as_ref().map(|x| &x[..]),
+                           Some("St. Vincent-St. Mary HS (OH)"));
+            }
+            _ => panic!("expected a TeamRoster row"),
+        }
     }
 
     #[test]
     fn test_find_idx() {
+        let transport = fixture("commonteamroster.json");
         let payload = Query::TeamRosterQuery {
             teamid: "1610612739".to_owned(),
-            season: "2015-1f6".to_owned(),
+            season: "2015-16".to_owned(),
         };
-        let data: Value = Stat::post_query(TEAMROSTER_BASE_URL.to_owned(), payload).unwrap();
+        let data: Value = Stat::post_query_with(&*transport,
+                                                TEAMROSTER_BASE_URL.to_owned(),
+                                                payload)
+                              .unwrap();
         let data = data.as_object().expect("could not objectify");
         let data = data.get("resultSets").expect("could not resultSet").as_array().unwrap();
         let data = data[0].as_object().expect("could not objectify");
@@ -75,4 +166,1177 @@ mod tests {
 
     }
 
+    #[test]
+    fn test_cassette_normalize() {
+        let a = Url::parse("http://stats.nba.com/stats/playbyplay/?gameid=0041400106&startperiod=0&endperiod=14")
+                    .unwrap();
+        let b = Url::parse("http://stats.nba.com/stats/playbyplay/?EndPeriod=14&GameID=0041400106&StartPeriod=0")
+                    .unwrap();
+        assert_eq!(cassette::normalize(&a), cassette::normalize(&b));
+        assert_eq!(cassette::normalize(&a),
+                   "playbyplay?endperiod=14&gameid=0041400106&startperiod=0");
+    }
+
+    #[test]
+    fn test_cassette_unrecorded_query() {
+        let transport = ReplayTransport::load(format!("{}/fixtures/playbyplay.json",
+                                                      env!("CARGO_MANIFEST_DIR")))
+                            .unwrap();
+        let payload = Query::PlayByPlayQuery {
+            gameid: "0041400107".to_owned(),
+            startperiod: "0".to_owned(),
+            endperiod: "14".to_owned(),
+        };
+        match Stat::post_query_with(&transport, PLAYBYPLAY_BASE_URL.to_owned(), payload) {
+            Err(NBAError::MissingResponse(_)) => {}
+            other => panic!("expected a missing response, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_memory_transport() {
+        let mut transport = MemoryTransport::new();
+        transport.insert(TEAMROSTER_BASE_URL,
+                         r#"{"resultSets": [{"name": "CommonTeamRoster",
+                              "headers": ["TeamID", "SEASON", "LeagueID", "PLAYER", "NUM",
+                                          "POSITION", "HEIGHT", "WEIGHT", "BIRTH_DATE", "AGE",
+                                          "EXP", "SCHOOL", "PLAYER_ID"],
+                              "rowSet": [["1610612739", "2015", "00", "LeBron James", "23",
+                                          "F", "6-8", "250", "DEC 30, 1984", 31, "12", "",
+                                          "2544"]]}]}"#);
+        let payload = Query::TeamRosterQuery {
+            teamid: "1610612739".to_owned(),
+            season: "2015-16".to_owned(),
+        };
+        let stats = Stat::get_data_with(&transport, StatType::TeamRoster, payload).unwrap();
+        assert_eq!(stats.len(), 1);
+        match stats[0] {
+            Stat::TeamRoster(ref entry) => {
+                assert_eq!(entry.player.as_ref().map(|x| &x[..]), Some("LeBron James"));
+                assert_eq!(entry.age, Some(31));
+            }
+            _ => panic!("expected a TeamRoster row"),
+        }
+    }
+
+    #[test]
+    fn test_transport_bad_status() {
+        let mut transport = MemoryTransport::new();
+        transport.insert_response(TEAMROSTER_BASE_URL,
+                                  Response {
+                                      status: 400,
+                                      body: "TeamID is required".to_owned(),
+                                  });
+        let payload = Query::TeamRosterQuery {
+            teamid: "1610612739".to_owned(),
+            season: "2015-16".to_owned(),
+        };
+        match Stat::post_query_with(&transport, TEAMROSTER_BASE_URL.to_owned(), payload) {
+            Err(NBAError::HttpStatusError(400)) => {}
+            other => panic!("expected a status error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_check_payload_rejects_bad_queries() {
+        let payload = Query::PlayByPlayQuery {
+            gameid: "abc0041400106xyz".to_owned(),
+            startperiod: "0".to_owned(),
+            endperiod: "14".to_owned(),
+        };
+        match Stat::check_payload(&payload) {
+            Err(NBAError::InvalidQuery { field, ref value, .. }) => {
+                assert_eq!(field, "gameid");
+                assert_eq!(value, "abc0041400106xyz");
+            }
+            other => panic!("expected an invalid gameid, got {:?}", other),
+        }
+
+        let payload = Query::PlayByPlayQuery {
+            gameid: "0041400106".to_owned(),
+            startperiod: "0".to_owned(),
+            endperiod: "99".to_owned(),
+        };
+        match Stat::get_data_with(&MemoryTransport::new(), StatType::PlayByPlay, payload) {
+            Err(NBAError::InvalidQuery { field: "endperiod", .. }) => {}
+            other => panic!("expected an invalid endperiod, got {:?}", other),
+        }
+
+        let payload = Query::TeamRosterQuery {
+            teamid: "1610612739".to_owned(),
+            season: "2015-1f6".to_owned(),
+        };
+        assert!(Stat::check_payload(&payload).is_err());
+    }
+
+    #[test]
+    fn test_malformed_responses() {
+        let payload = || {
+            Query::EastConfStandingsQuery {
+                leagueid: "00".to_owned(),
+                gamedate: "02/21/2015".to_owned(),
+                dayoffset: "0".to_owned(),
+            }
+        };
+        let mut transport = MemoryTransport::new();
+
+        transport.insert(EASTCONFSTANDINGS_BASE_URL, "[]");
+        match Stat::get_data_with(&transport, StatType::EastConfStandings, payload()) {
+            Err(NBAError::ObjectError) => {}
+            other => panic!("expected an object error, got {:?}", other),
+        }
+
+        transport.insert(EASTCONFSTANDINGS_BASE_URL, r#"{"resultSets": []}"#);
+        match Stat::get_data_with(&transport, StatType::EastConfStandings, payload()) {
+            Err(NBAError::MissingField("EastConfStandingsByDay")) => {}
+            other => panic!("expected a missing result set, got {:?}", other),
+        }
+
+        transport.insert(TEAMROSTER_BASE_URL,
+                         r#"{"resultSets": [{"name": "CommonTeamRoster",
+                                             "headers": ["TeamID", "SEASON", "LeagueID", "PLAYER",
+                                                         "NUM", "POSITION", "HEIGHT", "WEIGHT",
+                                                         "BIRTH_DATE", "AGE", "EXP", "SCHOOL",
+                                                         "PLAYER_ID"],
+                                             "rowSet": [[1610612739, "2015"]]}]}"#);
+        let payload = Query::TeamRosterQuery {
+            teamid: "1610612739".to_owned(),
+            season: "2015-16".to_owned(),
+        };
+        match Stat::get_data_with(&transport, StatType::TeamRoster, payload) {
+            Err(NBAError::ArrayError) => {}
+            other => panic!("expected an array error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_teams() {
+        assert_eq!(teams::all().len(), 30);
+        let cavs = teams::by_abbr("CLE").unwrap();
+        assert_eq!(cavs.id, "1610612739");
+        assert_eq!(cavs.full_name(), "Cleveland Cavaliers");
+        assert_eq!(teams::by_id("1610612739"), Some(cavs));
+        assert_eq!(teams::by_name("cavaliers"), Some(cavs));
+        assert_eq!(teams::by_code("cavaliers"), Some(cavs));
+        assert_eq!(teams::find("Cleveland"), Some(cavs));
+        assert_eq!(teams::by_city("Los Angeles").len(), 2);
+        assert_eq!(teams::find("Los Angeles"), None);
+        assert_eq!(teams::by_conference("East").len(), 15);
+        assert_eq!(teams::by_conference("Western").len(), 15);
+        assert_eq!(teams::by_division("Central").len(), 5);
+    }
+
+    #[test]
+    fn test_teamroster_by_abbreviation() {
+        let transport = fixture("commonteamroster.json");
+        let payload = Query::TeamRosterQuery {
+            teamid: "CLE".to_owned(),
+            season: "2015-16".to_owned(),
+        };
+        let stats = Stat::get_data_with(&*transport, StatType::TeamRoster, payload).unwrap();
+        assert_eq!(stats.len(), 5);
+        assert_eq!(stats[0].team().map(|x| &x.abbr[..]), Some("CLE"));
+
+        let payload = Query::TeamRosterQuery {
+            teamid: "Nowhere".to_owned(),
+            season: "2015-16".to_owned(),
+        };
+        assert!(Stat::check_payload(&payload).is_err());
+    }
+
+    #[test]
+    fn test_gameheader_teams() {
+        let transport = fixture("scoreboard.json");
+        let payload = Query::GameHeaderQuery {
+            leagueid: "00".to_owned(),
+            gamedate: "02/21/2015".to_owned(),
+            dayoffset: "0".to_owned(),
+        };
+        let stats = Stat::get_data_with(&*transport, StatType::GameHeader, payload).unwrap();
+        assert_eq!(stats[1].home_team().map(|x| &x.name[..]), Some("Hawks"));
+        assert_eq!(stats[1].visitor_team().map(|x| &x.name[..]), Some("Grizzlies"));
+    }
+
+    #[test]
+    fn test_params() {
+        assert_eq!("2015-16".parse::<Season>().unwrap(), Season::new(2015).unwrap());
+        assert_eq!(Season::new(1999).unwrap().to_string(), "1999-00");
+        assert!("2015-17".parse::<Season>().is_err());
+        assert!(Season::new(u16::max_value()).is_err());
+        assert!("9999-00".parse::<Season>().is_err());
+        assert_eq!("02/21/2015".parse::<GameDate>().unwrap(),
+                   GameDate::new(2015, 2, 21).unwrap());
+        assert!(GameDate::new(2015, 2, 29).is_err());
+        assert!(GameDate::new(2016, 2, 29).is_ok());
+        assert!("2/21/2015".parse::<GameDate>().is_err());
+        assert!(Period::new(15).is_err());
+        assert_eq!(DayOffset::new(99).unwrap().to_string(), "99");
+        assert!(DayOffset::new(100).is_err());
+        assert!(GameId::new("abc0041400106xyz").is_err());
+        assert_eq!("20".parse::<League>().unwrap(), League::DLeague);
+    }
+
+    #[test]
+    fn test_builders_match_wire_params() {
+        let built = PlayByPlayQueryBuilder::new(GameId::new("0041400106").unwrap()).build();
+        let manual = Query::PlayByPlayQuery {
+            gameid: "0041400106".to_owned(),
+            startperiod: "0".to_owned(),
+            endperiod: "14".to_owned(),
+        };
+        assert_eq!(built.params(), manual.params());
+
+        let built = ScoreboardQueryBuilder::new(GameDate::new(2015, 2, 21).unwrap())
+                        .west_conf_standings();
+        let manual = Query::WestConfStandingsQuery {
+            leagueid: "00".to_owned(),
+            gamedate: "02/21/2015".to_owned(),
+            dayoffset: "0".to_owned(),
+        };
+        assert_eq!(built.params(), manual.params());
+
+        let cle = teams::by_abbr("CLE").unwrap();
+        let built = TeamRosterQueryBuilder::new(Season::new(2015).unwrap(), cle).build();
+        let manual = Query::TeamRosterQuery {
+            teamid: "1610612739".to_owned(),
+            season: "2015-16".to_owned(),
+        };
+        assert_eq!(built.params(), manual.params());
+        assert!(Stat::check_payload(&built).is_ok());
+
+        let built = ScoreboardQueryBuilder::new(GameDate::new(2015, 7, 1).unwrap())
+                        .league(League::WNBA)
+                        .game_header();
+        assert!(Stat::check_payload(&built).is_ok());
+    }
+
+    #[test]
+    fn test_fetch() {
+        let transport = fixture("scoreboard.json");
+        let query = ScoreboardQueryBuilder::new(GameDate::new(2015, 2, 21).unwrap());
+        let games = fetch_with::<GameHeaderEndpoint>(&*transport, query.game_header()).unwrap();
+        assert_eq!(games.len(), 2);
+        assert_eq!(games[0].game_status_text.as_ref().map(|x| &x[..]), Some("Final"));
+        let lines = fetch_with::<LineScoreEndpoint>(&*transport, query.game_header()).unwrap();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0].team().map(|x| &x.abbr[..]), Some("CHI"));
+
+        let east = fetch_with::<EastConfStandingsEndpoint>(&*transport,
+                                                           query.east_conf_standings())
+                       .unwrap();
+        assert_eq!(east[0].team().map(|x| &x.abbr[..]), Some("ATL"));
+        assert_eq!(east[0].wins, Some(45));
+
+        let transport = fixture("commonteamroster.json");
+        let cle = teams::by_abbr("CLE").unwrap();
+        let query = TeamRosterQueryBuilder::new(Season::new(2015).unwrap(), cle).build();
+        let roster = fetch_with::<TeamRosterEndpoint>(&*transport, query).unwrap();
+        assert_eq!(roster[0].player.as_ref().map(|x| &x[..]), Some("Kyrie Irving"));
+    }
+
+    #[test]
+    fn test_boxscores() {
+        let query = BoxScoreQueryBuilder::new(GameId::new("0041400106").unwrap());
+
+        let transport = fixture("boxscoretraditionalv2.json");
+        let players = fetch_with::<BoxScoreTraditionalPlayersEndpoint>(&*transport,
+                                                                       query.traditional())
+                          .unwrap();
+        assert_eq!(players.len(), 9);
+        assert_eq!(players[0].player_name.as_ref().map(|x| &x[..]), Some("Jeff Teague"));
+        assert_eq!(players[0].pts, Some(17));
+        assert_eq!(players[0].plus_minus, Some(9));
+        let dnp = &players[4];
+        assert_eq!(dnp.comment.as_ref().map(|x| &x[..]), Some("DNP - Coach's Decision"));
+        assert_eq!(dnp.min, None);
+        assert_eq!(dnp.pts, None);
+
+        let teams = Stat::get_data_with(&*transport,
+                                        StatType::BoxScoreTraditionalTeams,
+                                        query.traditional())
+                        .unwrap();
+        match teams[1] {
+            Stat::BoxScoreTraditionalTeam(ref team) => {
+                assert_eq!(team.team_abbreviation.as_ref().map(|x| &x[..]), Some("BKN"));
+                assert_eq!(team.pts, Some(87));
+                assert_eq!(team.tov, Some(14));
+            }
+            ref other => panic!("expected a team box score, got {:?}", other),
+        }
+
+        let transport = fixture("boxscoreadvancedv2.json");
+        let teams = fetch_with::<BoxScoreAdvancedTeamsEndpoint>(&*transport, query.advanced())
+                        .unwrap();
+        assert_eq!(teams[0].off_rating, Some(119.4));
+        assert_eq!(teams[0].pace, Some(93.0));
+
+        let transport = fixture("boxscorefourfactorsv2.json");
+        let players = fetch_with::<BoxScoreFourFactorsPlayersEndpoint>(&*transport,
+                                                                       query.four_factors())
+                          .unwrap();
+        assert_eq!(players[0].efg_pct, Some(0.536));
+        assert_eq!(players[0].opp_oreb_pct, Some(0.19));
+    }
+
+    #[test]
+    fn test_shotchart() {
+        let transport = fixture("shotchartdetail.json");
+        let query = ShotChartQueryBuilder::new(Season::new(2014).unwrap())
+                        .season_type(SeasonType::Playoffs)
+                        .team(teams::by_abbr("ATL").unwrap())
+                        .player("201952")
+                        .game(GameId::new("0041400106").unwrap())
+                        .build();
+        let shots = fetch_with::<ShotChartEndpoint>(&*transport, query).unwrap();
+        assert_eq!(shots.len(), 14);
+        assert_eq!(shots.iter().filter(|x| x.made()).count(), 7);
+
+        let corner = &shots[4];
+        assert_eq!(corner.x_feet(), Some(-22.6));
+        assert_eq!(corner.zone(), Some(Zone::RightCorner3));
+        for shot in &shots {
+            let (x, y) = (shot.x_feet().unwrap(), shot.y_feet().unwrap());
+            assert_eq!(Some(Zone::from_location(x, y)), shot.zone());
+            assert_eq!(shot.distance_feet().map(|x| x as u64), shot.shot_distance);
+        }
+
+        let zones = shotchart::by_zone(&shots);
+        let threes: u32 = zones.iter().filter(|x| x.zone.is_three()).map(|x| x.attempts).sum();
+        assert_eq!(threes, 3);
+        assert_eq!(zones[0].zone, Zone::RestrictedArea);
+        assert_eq!(zones[0].fg_pct(), Some(0.75));
+
+        assert_eq!(Zone::from_location(0.0, 45.0), Zone::Backcourt);
+        assert_eq!(Zone::from_location(23.0, 2.0), Zone::LeftCorner3);
+    }
+
+    #[test]
+    fn test_player_endpoints() {
+        let cle = teams::by_abbr("CLE").unwrap();
+        let query = TeamRosterQueryBuilder::new(Season::new(2015).unwrap(), cle).build();
+        let roster = fetch_with::<TeamRosterEndpoint>(&*fixture("commonteamroster.json"), query)
+                         .unwrap();
+        let lebron = &roster[1];
+        let log = lebron.game_log_with(&*fixture("playergamelog.json")).unwrap();
+        assert_eq!(log.len(), 5);
+        assert_eq!(log[0].matchup.as_ref().map(|x| &x[..]), Some("CLE vs. NYK"));
+        assert_eq!(log[0].pts, Some(23));
+        assert_eq!(log[4].plus_minus, Some(30));
+        // The game ids feed straight back into the other game queries.
+        let game_id = GameId::new(log[0].game_id.as_ref().unwrap()).unwrap();
+        assert_eq!(game_id.as_str(), "0021500069");
+
+        let player = PlayerQueryBuilder::new("2544");
+        let transport = fixture("playercareerstats.json");
+        let seasons = fetch_with::<PlayerSeasonTotalsEndpoint>(&*transport, player.career_stats())
+                          .unwrap();
+        assert_eq!(seasons.len(), 3);
+        assert_eq!(seasons[2].team_abbreviation.as_ref().map(|x| &x[..]), Some("CLE"));
+        let career = Stat::get_data_with(&*transport,
+                                         StatType::PlayerPostSeasonCareerTotals,
+                                         player.career_stats())
+                         .unwrap();
+        match career[0] {
+            Stat::PlayerPostSeasonCareerTotals(ref totals) => assert_eq!(totals.gp, Some(63)),
+            ref other => panic!("expected career totals, got {:?}", other),
+        }
+
+        let info = fetch_with::<CommonPlayerInfoEndpoint>(&*fixture("commonplayerinfo.json"),
+                                                          player.info())
+                       .unwrap();
+        assert_eq!(info[0].display_first_last.as_ref().map(|x| &x[..]),
+                   Some("LeBron James"));
+        assert_eq!(info[0].team().map(|x| &x.abbr[..]), Some("CLE"));
+        assert_eq!(info[0].from_year, Some(2003));
+    }
+
+    #[test]
+    fn test_game_logs() {
+        let atl = teams::by_abbr("ATL").unwrap();
+        let query = TeamGameLogQueryBuilder::new(atl, Season::new(2014).unwrap())
+                        .season_type(SeasonType::Playoffs)
+                        .build();
+        let games = fetch_with::<TeamGameLogEndpoint>(&*fixture("teamgamelog.json"), query)
+                        .unwrap();
+        assert_eq!(games.len(), 6);
+        assert_eq!(games[0].team(), Some(atl));
+        assert_eq!((games[0].wins, games[0].losses), (Some(4), Some(2)));
+        assert_eq!(games[2].wl.as_ref().map(|x| &x[..]), Some("L"));
+
+        // Game ids found through the game log replay straight into the play-by-play.
+        let query = games[0].play_by_play_query().unwrap();
+        let events = fetch_with::<PlayByPlayEndpoint>(&*fixture("playbyplay.json"), query)
+                         .unwrap();
+        assert_eq!(events.len(), 49);
+
+        let query = LeagueGameFinderQueryBuilder::new(Season::new(2014).unwrap())
+                        .season_type(SeasonType::Playoffs)
+                        .team(atl)
+                        .date_from(GameDate::new(2015, 4, 27).unwrap())
+                        .date_to(GameDate::new(2015, 5, 1).unwrap())
+                        .build();
+        let found = Stat::get_data_with(&*fixture("leaguegamefinder.json"),
+                                        StatType::LeagueGameFinder,
+                                        query)
+                        .unwrap();
+        assert_eq!(found.len(), 3);
+        match found[2] {
+            Stat::LeagueGameFinder(ref game) => {
+                assert_eq!(game.game_id().unwrap().as_str(), "0041400104");
+                assert_eq!(game.plus_minus, Some(-5));
+            }
+            ref other => panic!("expected a game finder result, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_league_stats() {
+        let query = LeagueLeadersQueryBuilder::new(Season::new(2014).unwrap()).build();
+        let leaders = fetch_with::<LeagueLeadersEndpoint>(&*fixture("leagueleaders.json"), query)
+                          .unwrap();
+        assert_eq!(leaders.len(), 5);
+        assert_eq!(leaders[0].player.as_ref().map(|x| &x[..]), Some("Russell Westbrook"));
+        assert_eq!(leaders[0].pts, Some(28.1));
+        assert_eq!(leaders[4].rank, Some(5));
+
+        let query = LeagueDashQueryBuilder::new(Season::new(2014).unwrap())
+                        .measure_type(MeasureType::Advanced)
+                        .date_from(GameDate::new(2015, 2, 1).unwrap())
+                        .date_to(GameDate::new(2015, 2, 28).unwrap());
+        let transport = fixture("leaguedashplayerstats.json");
+        let players = fetch_with::<LeagueDashPlayerStatsEndpoint>(&*transport, query.players())
+                          .unwrap();
+        assert_eq!(players[1].player_name.as_ref().map(|x| &x[..]), Some("Stephen Curry"));
+        assert_eq!(players[1].stat("NET_RATING"), Some(16.7));
+        assert_eq!(players[1].stat("NET_RATING_RANK"), Some(2.0));
+        assert_eq!(players[1].stat("CFPARAMS"), None);
+        assert_eq!(players[2].team().map(|x| &x.abbr[..]), Some("ATL"));
+
+        let query = LeagueDashQueryBuilder::new(Season::new(2014).unwrap());
+        let teams = Stat::get_data_with(&*fixture("leaguedashteamstats.json"),
+                                        StatType::LeagueDashTeamStats,
+                                        query.teams())
+                        .unwrap();
+        match teams[0] {
+            Stat::LeagueDashTeamStats(ref team) => {
+                assert_eq!(team.wins, Some(67));
+                assert_eq!(team.stat("PTS"), Some(110.0));
+            }
+            ref other => panic!("expected a team dashboard row, got {:?}", other),
+        }
+
+        let bad = Query::LeagueLeadersQuery {
+            leagueid: "00".to_owned(),
+            season: "2014-15".to_owned(),
+            seasontype: "Regular Season".to_owned(),
+            permode: "PerGame".to_owned(),
+            statcategory: "DUNKS".to_owned(),
+        };
+        match Stat::check_payload(&bad) {
+            Err(NBAError::InvalidQuery { field: "statcategory", .. }) => {}
+            other => panic!("expected an invalid statcategory, got {:?}", other),
+        }
+        assert_eq!("Four Factors".parse::<MeasureType>().unwrap(), MeasureType::FourFactors);
+        assert!("Per48".parse::<PerMode>().is_err());
+    }
+
+    #[test]
+    fn test_scoreboard() {
+        let transport = fixture("scoreboard.json");
+        let query = ScoreboardQueryBuilder::new(GameDate::new(2015, 2, 21).unwrap());
+        let board = Scoreboard::fetch_with(&*transport, query.game_header()).unwrap();
+        assert_eq!(board.game_header.len(), 2);
+        assert_eq!(board.line_score.len(), 4);
+        assert_eq!(board.series_standings[1].series_leader.as_ref().map(|x| &x[..]),
+                   Some("Tied"));
+        assert_eq!(board.last_meeting[1]
+                        .last_game_visitor_team_abbreviation
+                        .as_ref()
+                        .map(|x| &x[..]),
+                   Some("ATL"));
+        assert_eq!(board.east_conf_standings.len(), 3);
+        assert_eq!(board.west_conf_standings[0].team().map(|x| &x.abbr[..]), Some("GSW"));
+        assert_eq!(board.available[0].pt_available, Some(true));
+        assert_eq!(board.game("0021400830").and_then(|x| x.home_team()).map(|x| &x.abbr[..]),
+                   Some("ATL"));
+
+        let (home, visitor) = board.line_scores("0021400830").unwrap();
+        assert_eq!(home.team_abbreviation.as_ref().map(|x| &x[..]), Some("ATL"));
+        assert_eq!(visitor.team_wins_losses.as_ref().map(|x| &x[..]), Some("40-15"));
+        let periods = board.game("0021400830").unwrap().live_period.unwrap();
+        assert_eq!(home.points_by_period(periods),
+                   vec![Some(22), Some(24), Some(27), Some(21), Some(12)]);
+        assert_eq!(visitor.period_points(5), Some(9));
+        assert_eq!(home.period_points(6), Some(0));
+        assert_eq!(board.score("0021400830"), Some((106, 103)));
+        assert_eq!(board.score("0021400829"), Some((99, 104)));
+
+        // Result sets are found by name, wherever they sit in the response.
+        let path = format!("{}/fixtures/scoreboard.json", env!("CARGO_MANIFEST_DIR"));
+        let recorded = cassette::Cassette::load(path).unwrap();
+        let mut data: Value = serde_json::from_str(&recorded.interactions()[0].body).unwrap();
+        {
+            let sets = data.as_object_mut().unwrap().get_mut("resultSets").unwrap();
+            let reversed: Vec<Value> = sets.as_array().unwrap().iter().rev().cloned().collect();
+            *sets = Value::Array(reversed);
+        }
+        let mut reordered = MemoryTransport::new();
+        reordered.insert(GAMEHEADER_BASE_URL, &serde_json::to_string(&data).unwrap());
+        assert_eq!(Scoreboard::fetch_with(&reordered, query.game_header()).unwrap(),
+                   board);
+        let east = fetch_with::<EastConfStandingsEndpoint>(&reordered,
+                                                           query.east_conf_standings())
+                       .unwrap();
+        assert_eq!(east, board.east_conf_standings);
+
+        let query = PlayByPlayQueryBuilder::new(GameId::new("0041400106").unwrap()).build();
+        match Scoreboard::fetch_with(&reordered, query) {
+            Err(NBAError::InvalidQuery { field: "query", .. }) => {}
+            other => panic!("expected a non-scoreboard query to be refused, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_event_kinds() {
+        let events = playbyplay_events();
+        let kinds: Vec<EventKind> = events.iter().map(|x| x.kind().unwrap()).collect();
+        assert_eq!(kinds[0], EventKind::PeriodStart);
+        assert_eq!(kinds[1], EventKind::JumpBall);
+        assert_eq!(kinds[2], EventKind::MadeShot(ShotType::JumpShot));
+        assert_eq!(kinds[7], EventKind::MadeShot(ShotType::Layup));
+        assert_eq!(kinds[8], EventKind::Turnover(TurnoverType::BadPass));
+        assert_eq!(kinds[9], EventKind::MadeShot(ShotType::Dunk));
+        assert_eq!(kinds[10], EventKind::Foul(FoulType::Shooting));
+        assert_eq!(kinds[11],
+                   EventKind::FreeThrow(FreeThrowType::Regular { number: 1, of: 2 }));
+        assert_eq!(kinds[15], EventKind::Timeout);
+        assert_eq!(kinds[16], EventKind::Substitution);
+        assert_eq!(kinds[21], EventKind::MadeShot(ShotType::HookShot));
+        assert_eq!(kinds[25], EventKind::Foul(FoulType::Offensive));
+        assert_eq!(kinds[26], EventKind::Turnover(TurnoverType::OffensiveFoul));
+        assert_eq!(kinds[30], EventKind::MissedShot(ShotType::Layup));
+        assert_eq!(kinds[32], EventKind::Turnover(TurnoverType::Traveling));
+        assert_eq!(kinds[39], EventKind::PeriodEnd);
+        assert_eq!(kinds.iter().filter(|x| x.is_field_goal()).count(), 20);
+
+        assert!(FreeThrowType::new(12).is_last());
+        assert!(!FreeThrowType::new(13).is_last());
+        assert!(!FoulType::new(11).is_team_foul());
+        assert_eq!(EventKind::new(18, 4), EventKind::InstantReplay);
+        assert_eq!(EventKind::new(7, 2), EventKind::Violation(ViolationType::Goaltending));
+        assert_eq!(EventKind::new(1, 999), EventKind::MadeShot(ShotType::Other(999)));
+        assert_eq!(EventKind::new(42, 1), EventKind::Unknown(42, 1));
+    }
+
+    #[test]
+    fn test_descriptions() {
+        let events = playbyplay_events();
+
+        assert_eq!(events[0].details, Description::default());
+        assert!(events[0].player1.is_none());
+        assert_eq!(events[1].details.player, Some("Lopez".to_owned()));
+
+        let three = &events[2];
+        assert_eq!(three.details.player, Some("Korver".to_owned()));
+        assert_eq!(three.details.distance, Some(25));
+        assert_eq!(three.details.points, Some(3));
+        assert_eq!(three.details.assist, Some("Teague".to_owned()));
+        assert_eq!(three.assister().unwrap().id, "201952");
+        assert!(!three.details.missed);
+
+        let blocked = &events[5];
+        assert!(blocked.details.missed);
+        assert_eq!(blocked.details.player, Some("Horford".to_owned()));
+        assert_eq!(blocked.details.distance, Some(15));
+        assert_eq!(blocked.details.block, Some("Lopez".to_owned()));
+        assert_eq!(blocked.blocker().unwrap().id, "201572");
+        assert!(blocked.blocker().unwrap().is_home());
+
+        assert_eq!(events[7].details.player, Some("Horford".to_owned()));
+        assert_eq!(events[7].details.points, Some(2));
+
+        let turnover = &events[8];
+        assert_eq!(turnover.details.player, Some("Johnson".to_owned()));
+        assert_eq!(turnover.details.steal, Some("Carroll".to_owned()));
+        assert_eq!(turnover.details.turnovers, Some(1));
+        assert_eq!(turnover.details.personal_fouls, None);
+        assert_eq!(turnover.stealer().unwrap().name, Some("DeMarre Carroll".to_owned()));
+
+        let foul = &events[10];
+        assert_eq!(foul.details.player, Some("Millsap".to_owned()));
+        assert_eq!(foul.details.personal_fouls, Some(1));
+        assert_eq!(foul.details.team_fouls, Some(1));
+        assert!(!foul.details.penalty);
+        assert_eq!(foul.fouled().unwrap().id, "201572");
+
+        let timeout = &events[15];
+        assert_eq!(timeout.details.player, Some("Nets".to_owned()));
+        assert!(timeout.player1.as_ref().unwrap().is_team());
+
+        let sub = &events[16];
+        assert_eq!(sub.details.player, Some("Williams".to_owned()));
+        assert_eq!(sub.sub_in().unwrap().name, Some("Jarrett Jack".to_owned()));
+        assert!(sub.sub_out().unwrap().is_home());
+        assert!(sub.assister().is_none());
+
+        assert_eq!(events[25].details.personal_fouls, Some(1));
+        assert_eq!(events[25].details.team_fouls, None);
+
+        let penalty = description::parse("Horford P.FOUL (P3.PN) (B.Adams)");
+        assert_eq!(penalty.player, Some("Horford".to_owned()));
+        assert_eq!(penalty.personal_fouls, Some(3));
+        assert!(penalty.penalty);
+        let rebound = description::parse("Millsap REBOUND (Off:0 Def:1)");
+        assert_eq!(rebound.player, Some("Millsap".to_owned()));
+        assert_eq!(description::parse("Hardaway Jr. 3PT Jump Shot (3 PTS)").player,
+                   Some("Hardaway Jr.".to_owned()));
+    }
+
+    #[test]
+    fn test_scores() {
+        let events = playbyplay_events();
+        assert_eq!(events[0].score, None);
+        assert_eq!(events[2].score, Some(Score { home: 0, away: 3 }));
+        assert_eq!(events[2].scoremargin, Some(-3));
+        assert_eq!(events[5].score, Some(Score { home: 0, away: 3 }));
+        assert_eq!(events[11].score, Some(Score { home: 1, away: 7 }));
+        assert_eq!(events[12].scoremargin, Some(-6));
+        assert_eq!(events[48].score, Some(Score { home: 16, away: 19 }));
+
+        let events = events_from_rows(r#"[
+            ["1", 1, 1, 1, 4, "", "1:02", "Lopez Layup (20 PTS)", null, null, "102 - 102", "TIE",
+             0, 0],
+            ["1", 2, 4, 0, 4, "", "0:40", null, null, "Millsap REBOUND", null, null, 0, 0],
+            ["1", 3, 1, 1, 4, "", "0:31", null, null, "Korver Layup", "104 - 102", "-2",
+             0, 0]]"#);
+        assert_eq!(events[0].scoremargin, Some(0));
+        assert_eq!(events[1].score, Some(Score { home: 102, away: 102 }));
+        assert_eq!(events[1].scoremargin, Some(0));
+        assert_eq!(events[2].scoremargin, Some(-2));
+        assert!(events[2].player1.is_none());
+
+        assert_eq!(Score::parse("98 - 102").unwrap().margin(), 4);
+        assert_eq!(Score::parse("TIE"), None);
+    }
+
+    #[test]
+    fn test_game_clock() {
+        let events = playbyplay_events();
+        assert_eq!(events[2].pctimestring, Some(GameClock::new(11, 41)));
+        assert_eq!(events[2].elapsed(), Some(19));
+        assert_eq!(events[2].time_left(), Some(2861));
+        assert_eq!(events[39].elapsed(), Some(720));
+        assert!(events.windows(2).all(|x| x[0].elapsed() <= x[1].elapsed()));
+
+        let date = GameDate::new(2015, 5, 1).unwrap();
+        let tip = events[0].wall_clock(date).unwrap();
+        assert_eq!(tip.to_string(), "8:07 PM");
+        assert_eq!(events[2].wall_clock(date).unwrap().minutes_since(&tip), Some(1));
+
+        let clock = GameClock::parse("4:32").unwrap();
+        assert_eq!(clock.elapsed(2), 720 + 448);
+        assert_eq!(clock.elapsed(5), 2880 + 28);
+        assert_eq!(clock.elapsed(6), 2880 + 300 + 28);
+        assert_eq!(clock.game_remaining(5), 272);
+        assert_eq!(GameClock::parse("0:04.5"), Some(GameClock::new(0, 4)));
+        assert_eq!(GameClock::parse("24.3"), Some(GameClock::new(0, 24)));
+        assert_eq!(GameClock::parse("1:75"), None);
+        assert_eq!(clock::period_start(5), clock::REGULATION_SECONDS);
+
+        let late = WallClock::parse("12:15 AM", date).unwrap();
+        assert!(late > WallClock::parse("11:50 PM", date).unwrap());
+        assert_eq!(late.minutes_since(&tip), Some(248));
+        assert_eq!(WallClock::parse("12:00 PM", date).unwrap().minutes(), 720);
+        assert_eq!(WallClock::parse("13:00 PM", date), None);
+    }
+
+    #[test]
+    fn test_replay() {
+        let events = playbyplay_events();
+        let states: Vec<GameState> = Replay::new(&events).map(|(_, state)| state).collect();
+        assert_eq!(states.len(), 49);
+
+        let mut home = states[0].home.on_court.clone();
+        home.sort();
+        assert_eq!(home, vec!["101114", "201152", "201572", "202711", "2207"]);
+        assert_eq!(states[0].visitor.on_court.len(), 5);
+        assert!(states[0].visitor.on_court.contains(&"201952".to_owned()));
+        assert_eq!(states[0].possession, None);
+
+        assert_eq!(states[1].possession, Some(Side::Visitor));
+        assert_eq!(states[2].possession, Some(Side::Home));
+        assert_eq!(states[2].score, Score { home: 0, away: 3 });
+        assert_eq!(states[3].possession, Some(Side::Home));
+        assert_eq!(states[4].possession, Some(Side::Visitor));
+        assert_eq!(states[8].possession, Some(Side::Visitor));
+        assert_eq!(states[10].visitor.fouls, 1);
+        assert_eq!(states[12].possession, Some(Side::Home));
+        assert_eq!(states[13].possession, Some(Side::Visitor));
+
+        assert_eq!(states[15].home.timeouts, GAME_TIMEOUTS - 1);
+        assert_eq!(states[15].home.short_timeouts, SHORT_TIMEOUTS);
+        assert_eq!(states[15].visitor.timeouts, GAME_TIMEOUTS);
+        assert!(states[16].home.on_court.contains(&"101127".to_owned()));
+        assert!(!states[16].home.on_court.contains(&"101114".to_owned()));
+        assert_eq!(states[16].home.on_court.len(), 5);
+        assert_eq!(states[25].visitor.fouls, 1);
+        assert_eq!(states[35].home.fouls, 2);
+        assert_eq!(states[37].possession, Some(Side::Home));
+
+        let second = &states[40];
+        assert_eq!(second.period, 2);
+        assert_eq!(second.home.fouls, 0);
+        assert_eq!(second.clock, Some(GameClock::new(12, 0)));
+        assert_eq!(second.home.on_court.len(), 5);
+        assert_eq!(second.visitor.on_court.len(), 5);
+        assert!(second.visitor.on_court.contains(&"203118".to_owned()));
+        assert!(second.visitor.on_court.contains(&"201143".to_owned()));
+        assert!(!second.visitor.on_court.contains(&"201952".to_owned()));
+        assert!(states[46].home.on_court.contains(&"101187".to_owned()));
+        assert_eq!(states[48].score, Score { home: 16, away: 19 });
+
+        // Flagrant and clear path free throws leave the ball with the fouled team.
+        let events = events_from_rows(r#"[
+            ["1", 1, 12, 0, 1, "", "12:00", null, null, null, null, null, 0, 0],
+            ["1", 2, 1, 1, 1, "", "11:40", "Lopez Layup (2 PTS)", null, null, "0 - 2", "2",
+             4, 1],
+            ["1", 3, 3, 18, 1, "", "11:30", null, null,
+             "Korver Free Throw Flagrant 1 of 2 (1 PTS)", "1 - 2", "1", 5, 2],
+            ["1", 4, 3, 19, 1, "", "11:30", null, null,
+             "Korver Free Throw Flagrant 2 of 2 (2 PTS)", "2 - 2", "TIE", 5, 2],
+            ["1", 5, 3, 27, 1, "", "11:10", null, null,
+             "Korver Free Throw Clear Path 1 of 1 (3 PTS)", "3 - 2", "-1", 5, 2],
+            ["1", 6, 3, 10, 1, "", "10:50", null, null, "Korver Free Throw 1 of 1 (4 PTS)",
+             "4 - 2", "-2", 5, 2]]"#);
+        let states: Vec<GameState> = Replay::new(&events).map(|(_, state)| state).collect();
+        assert_eq!(states[1].possession, Some(Side::Visitor));
+        assert_eq!(states[3].possession, Some(Side::Visitor));
+        assert_eq!(states[4].possession, Some(Side::Visitor));
+        assert_eq!(states[5].possession, Some(Side::Home));
+
+        // Overtime brings full timeouts back to three; twenty-second ones carry over.
+        let events = events_from_rows(r#"[
+            ["1", 1, 12, 0, 4, "", "12:00", null, null, null, "90 - 90", "TIE", 0, 0],
+            ["1", 2, 9, 1, 4, "", "5:00", "Nets Timeout: Regular", null, null, null, null, 2,
+             1610612751],
+            ["1", 3, 9, 2, 4, "", "3:00", "Nets Timeout: Short", null, null, null, null, 2,
+             1610612751],
+            ["1", 4, 13, 0, 4, "", "0:00", null, null, null, null, null, 0, 0],
+            ["1", 5, 12, 0, 5, "", "5:00", null, null, null, null, null, 0, 0]]"#);
+        let states: Vec<GameState> = Replay::new(&events).map(|(_, state)| state).collect();
+        assert_eq!(states[2].home.timeouts, GAME_TIMEOUTS - 1);
+        assert_eq!(states[2].home.short_timeouts, SHORT_TIMEOUTS - 1);
+        assert_eq!(states[4].home.timeouts, OVERTIME_TIMEOUTS);
+        assert_eq!(states[4].visitor.timeouts, OVERTIME_TIMEOUTS);
+        assert_eq!(states[4].home.short_timeouts, SHORT_TIMEOUTS - 1);
+        assert_eq!(states[4].visitor.short_timeouts, SHORT_TIMEOUTS);
+
+        // Player 2 goes out twice, so the second substitution really replaced player 1, who
+        // was only carried over from the first period.
+        let headers: Value = serde_json::from_str(r#"["GAME_ID", "EVENTNUM", "EVENTMSGTYPE",
+            "EVENTMSGACTIONTYPE", "PERIOD", "WCTIMESTRING", "PCTIMESTRING", "HOMEDESCRIPTION",
+            "NEUTRALDESCRIPTION", "VISITORDESCRIPTION", "SCORE", "SCOREMARGIN", "PERSON1TYPE",
+            "PLAYER1_ID", "PERSON2TYPE", "PLAYER2_ID"]"#)
+                                  .unwrap();
+        let rows: Value = serde_json::from_str(r#"[
+            ["1", 1, 12, 0, 1, "", "12:00", null, null, null, null, null, 0, 0, 0, 0],
+            ["1", 2, 4, 0, 1, "", "11:00", null, null, null, null, null, 4, 1, 0, 0],
+            ["1", 3, 4, 0, 1, "", "10:00", null, null, null, null, null, 4, 2, 0, 0],
+            ["1", 4, 4, 0, 1, "", "9:00", null, null, null, null, null, 4, 3, 0, 0],
+            ["1", 5, 4, 0, 1, "", "8:00", null, null, null, null, null, 4, 4, 0, 0],
+            ["1", 6, 4, 0, 1, "", "7:00", null, null, null, null, null, 4, 5, 0, 0],
+            ["1", 7, 13, 0, 1, "", "0:00", null, null, null, null, null, 0, 0, 0, 0],
+            ["1", 8, 12, 0, 2, "", "12:00", null, null, null, null, null, 0, 0, 0, 0],
+            ["1", 9, 4, 0, 2, "", "11:00", null, null, null, null, null, 4, 2, 0, 0],
+            ["1", 10, 4, 0, 2, "", "10:00", null, null, null, null, null, 4, 3, 0, 0],
+            ["1", 11, 4, 0, 2, "", "9:00", null, null, null, null, null, 4, 4, 0, 0],
+            ["1", 12, 4, 0, 2, "", "8:00", null, null, null, null, null, 4, 5, 0, 0],
+            ["1", 13, 8, 0, 2, "", "7:00", null, null, null, null, null, 4, 2, 4, 6],
+            ["1", 14, 8, 0, 2, "", "6:00", null, null, null, null, null, 4, 2, 4, 7]]"#)
+                               .unwrap();
+        let events: Vec<PlayByPlayEvent> = decode_rows(headers.as_array().unwrap(),
+                                                       rows.as_array().unwrap())
+                                               .unwrap();
+        let states: Vec<GameState> = Replay::new(&events).map(|(_, state)| state).collect();
+        let mut home = states[7].home.on_court.clone();
+        home.sort();
+        assert_eq!(home, vec!["1", "2", "3", "4", "5"]);
+        let mut home = states[13].home.on_court.clone();
+        home.sort();
+        assert_eq!(home, vec!["3", "4", "5", "6", "7"]);
+    }
+
+    #[test]
+    fn test_box_score_from_play_by_play() {
+        let payload = PlayByPlayQueryBuilder::new(GameId::new("0041400106").unwrap()).build();
+        let stats = Stat::get_data_with(&*fixture("playbyplay.json"),
+                                        StatType::PlayByPlay,
+                                        payload)
+                        .unwrap();
+        let box_score = BoxScore::from_stats(&stats);
+
+        let korver = box_score.player("2594").unwrap();
+        assert_eq!(korver.player_name, Some("Kyle Korver".to_owned()));
+        assert_eq!(korver.side, Side::Visitor);
+        assert_eq!((korver.totals.fgm, korver.totals.fga), (2, 4));
+        assert_eq!((korver.totals.fg3m, korver.totals.fg3a), (2, 4));
+        assert_eq!(korver.totals.pts, 6);
+        assert_eq!(korver.seconds, 1440);
+
+        let horford = &box_score.player("201143").unwrap().totals;
+        assert_eq!(horford.pts, 4);
+        assert_eq!((horford.ftm, horford.fta), (2, 2));
+        assert_eq!((horford.oreb, horford.dreb), (1, 2));
+
+        let lopez = &box_score.player("201572").unwrap().totals;
+        assert_eq!(lopez.pts, 3);
+        assert_eq!((lopez.ftm, lopez.fta), (1, 2));
+        assert_eq!((lopez.blk, lopez.pf, lopez.dreb), (1, 1, 1));
+
+        let carroll = &box_score.player("201960").unwrap().totals;
+        assert_eq!((carroll.stl, carroll.tov, carroll.pf, carroll.pts), (1, 1, 1, 2));
+        assert_eq!(box_score.player("201952").unwrap().totals.ast, 2);
+        assert_eq!(box_score.player("101114").unwrap().seconds, 130);
+        assert_eq!(box_score.player("201952").unwrap().seconds, 265);
+
+        assert_eq!(box_score.home.totals.pts, 16);
+        assert_eq!(box_score.visitor.totals.pts, 19);
+        assert_eq!(box_score.team(Side::Home).team_rebounds, 1);
+        assert_eq!(box_score.home.totals.tov, 2);
+        assert_eq!(box_score.visitor.totals.ast, 3);
+        let home_seconds: u64 = box_score.players
+                                         .iter()
+                                         .filter(|x| x.side == Side::Home)
+                                         .map(|x| x.seconds)
+                                         .sum();
+        assert_eq!(home_seconds, 5 * 1440);
+    }
+
+    #[test]
+    fn test_lineup_stints() {
+        let events = playbyplay_events();
+        let stints = lineups::stints(&events);
+        assert_eq!(stints.len(), 5);
+        assert_eq!(stints.iter().map(|x| x.seconds).sum::<u64>(), 1440);
+        assert_eq!(stints.iter().map(|x| x.home_points).sum::<u64>(), 16);
+        assert_eq!(stints.iter().map(|x| x.visitor_points).sum::<u64>(), 19);
+
+        let first = &stints[0];
+        assert_eq!(first.period, 1);
+        assert_eq!(first.start, Some(GameClock::new(12, 0)));
+        assert_eq!(first.end, Some(GameClock::new(9, 50)));
+        assert_eq!(first.seconds, 130);
+        assert_eq!(first.home.len(), 5);
+        assert!(first.home.contains(&"101114".to_owned()));
+        assert_eq!((first.home_points, first.visitor_points), (1, 9));
+        assert_eq!((first.home_possessions, first.visitor_possessions), (3, 4));
+        let split = first.split(Side::Visitor);
+        assert_eq!(split.plus_minus(), 8);
+        assert_eq!(split.offensive_rating(), Some(225.0));
+        assert_eq!(stints[1].start, Some(GameClock::new(9, 50)));
+        assert_eq!(stints[3].period, 2);
+
+        let splits = lineups::on_off(&stints);
+        let williams = splits.iter().find(|x| x.player_id == "101114").unwrap();
+        assert_eq!(williams.side, Side::Home);
+        assert_eq!(williams.on, first.split(Side::Home));
+        assert_eq!(williams.on.seconds + williams.off.seconds, 1440);
+        assert_eq!(williams.on.plus_minus() + williams.off.plus_minus(), -3);
+        assert!(williams.net_difference().is_some());
+
+        let units = lineups::lineup_splits(&stints);
+        assert_eq!(units.iter().filter(|x| x.side == Side::Home).count(), 3);
+        assert_eq!(units[0].stints, 1);
+        assert_eq!(units[0].split.seconds, 130);
+        assert!(units.iter().any(|x| x.side == Side::Home && x.stints == 3));
+        assert_eq!(stints.iter().map(|x| x.home_possessions).sum::<u64>(), 12);
+        assert_eq!(stints.iter().map(|x| x.visitor_possessions).sum::<u64>(), 12);
+
+        // A missed and-one is still one possession, ended by the defensive rebound.
+        let events = events_from_rows(r#"[
+            ["1", 1, 12, 0, 1, "", "12:00", null, null, null, null, null, 0, 0],
+            ["1", 2, 1, 1, 1, "", "11:40", "Lopez Layup (2 PTS)", null, null, "0 - 2", "2",
+             4, 1],
+            ["1", 3, 6, 2, 1, "", "11:40", null, null, "Korver S.FOUL (P1.T1)", null, null,
+             5, 2],
+            ["1", 4, 3, 10, 1, "", "11:40", "MISS Lopez Free Throw 1 of 1", null, null, null,
+             null, 4, 1],
+            ["1", 5, 4, 0, 1, "", "11:38", null, null, "Korver REBOUND (Off:0 Def:1)", null,
+             null, 5, 2],
+            ["1", 6, 1, 1, 1, "", "11:20", null, null, "Korver Layup (2 PTS)", "2 - 2", "TIE",
+             5, 2],
+            ["1", 7, 13, 0, 1, "", "0:00", null, null, null, null, null, 0, 0]]"#);
+        let stints = lineups::stints(&events);
+        assert_eq!(stints.len(), 1);
+        assert_eq!((stints[0].home_possessions, stints[0].visitor_possessions), (1, 1));
+        assert_eq!(stints[0].split(Side::Home).net_rating(), Some(0.0));
+
+        // End-of-period heaves count for stints no more than they do for pace.
+        let events = events_from_rows(r#"[
+            ["1", 1, 12, 0, 1, "", "12:00", null, null, null, null, null, 0, 0],
+            ["1", 2, 1, 1, 1, "", "0:02", "Lopez Layup", null, null, "0 - 2", "2", 4, 1],
+            ["1", 3, 2, 1, 1, "", "0:00", null, null, "MISS Korver 70' 3PT Jump Shot", null,
+             null, 5, 2],
+            ["1", 4, 13, 0, 1, "", "0:00", null, null, null, null, null, 0, 0]]"#);
+        let stints = lineups::stints(&events);
+        assert_eq!(stints.len(), 1);
+        assert_eq!((stints[0].home_possessions, stints[0].visitor_possessions), (1, 0));
+    }
+
+    #[test]
+    fn test_possessions() {
+        let events = playbyplay_events();
+        let all = possessions::possessions(&events);
+        assert_eq!(all.len(), 24);
+        assert_eq!(all[0].side, Side::Visitor);
+        assert_eq!(all[0].start, Some(GameClock::new(12, 0)));
+        assert_eq!(all[0].end, Some(GameClock::new(11, 41)));
+        assert_eq!(all[0].points, 3);
+        assert_eq!(all[1].start, Some(GameClock::new(11, 41)));
+        // Horford's offensive rebound keeps the possession going.
+        assert_eq!(all[2].points, 2);
+        assert_eq!(all[2].end, Some(GameClock::new(11, 1)));
+        // Millsap's and-one is one possession.
+        assert_eq!(all[10].side, Side::Visitor);
+        assert_eq!(all[10].points, 3);
+        assert_eq!(all[19].end, Some(GameClock::new(0, 0)));
+        assert_eq!(all[20].period, 2);
+        assert!(all.iter().all(|x| !x.heave));
+
+        let pace = Pace::from_events(&events);
+        assert_eq!((pace.home_possessions, pace.visitor_possessions), (12, 12));
+        assert_eq!(pace.seconds, 1440);
+        assert_eq!(pace.pace(), Some(24.0));
+        assert_eq!(pace.offensive_rating(Side::Visitor), Some(100.0 * 19.0 / 12.0));
+        assert_eq!(pace.defensive_rating(Side::Home), pace.offensive_rating(Side::Visitor));
+
+        let events = events_from_rows(r#"[
+            ["1", 1, 12, 0, 1, "", "12:00", null, null, null, null, null, 0, 0],
+            ["1", 2, 1, 1, 1, "", "0:02", "Lopez Layup", null, null, "0 - 2", "2", 4, 1],
+            ["1", 3, 3, 16, 1, "", "0:01", "Lopez Free Throw Technical", null, null, "0 - 3",
+             "3", 4, 1],
+            ["1", 4, 2, 1, 1, "", "0:00", null, null, "MISS Korver 70' 3PT Jump Shot", null,
+             null, 5, 2],
+            ["1", 5, 13, 0, 1, "", "0:00", null, null, null, null, null, 0, 0]]"#);
+        let all = possessions::possessions(&events);
+        assert_eq!(all.len(), 2);
+        assert_eq!(all[0].points, 2);
+        assert!(all[1].heave);
+        let pace = Pace::from_events(&events);
+        assert_eq!((pace.home_possessions, pace.visitor_possessions), (1, 0));
+        assert_eq!(pace.offensive_rating(Side::Home), Some(300.0));
+        assert_eq!(pace.offensive_rating(Side::Visitor), None);
+
+        // Without a start clock there's no telling a heave from a real possession.
+        let events = events_from_rows(r#"[
+            ["1", 1, 12, 0, 1, "", "12:00", null, null, null, null, null, 0, 0],
+            ["1", 2, 1, 1, 1, "", null, "Lopez Layup", null, null, "0 - 2", "2", 4, 1],
+            ["1", 3, 2, 1, 1, "", "0:01", null, null, "MISS Korver Jump Shot", null, null,
+             5, 2],
+            ["1", 4, 13, 0, 1, "", "0:00", null, null, null, null, null, 0, 0]]"#);
+        let all = possessions::possessions(&events);
+        assert_eq!(all[1].start, None);
+        assert!(!all[1].heave);
+    }
+
+    #[test]
+    fn test_win_probability() {
+        let events = playbyplay_events();
+        let model = WinProbabilityModel::new();
+        let series = winprob::series(&events, &model, None);
+        assert_eq!(series.len(), 49);
+        assert_eq!(series[0].home, 0.5);
+        assert_eq!(series[0].change, 0.0);
+        assert!(series[2].home < 0.5);
+        assert!(series[2].change < 0.0);
+        assert_eq!(series[2].margin, -3);
+        assert!(series.iter().all(|x| x.home > 0.0 && x.home < 1.0));
+        assert_eq!(series[2].home + series[2].visitor(), 1.0);
+        // Being favored helps early on.
+        let favored = winprob::series(&events, &model, Some(6.0));
+        assert!(favored[0].home > 0.6);
+        assert!(winprob::biggest_swing(&series).unwrap().change.abs() >=
+                series[2].change.abs());
+
+        let late = Features {
+            margin: 3.0,
+            seconds_left: 30.0,
+            possession: 0.0,
+            spread: 0.0,
+        };
+        let early = Features { seconds_left: 2000.0, ..late };
+        assert!(model.predict(&late) > model.predict(&early));
+        assert!(model.predict(&Features { possession: 1.0, ..late }) > model.predict(&late));
+
+        let json = model.to_json().unwrap();
+        assert_eq!(WinProbabilityModel::from_json(&json).unwrap(), model);
+        match WinProbabilityModel::from_json(r#"{"intercept": 0.1}"#) {
+            Err(NBAError::MissingField("margin")) => {}
+            other => panic!("expected a missing coefficient, got {:?}", other),
+        }
+
+        // The fixture stops at halftime, so it can't be labeled.
+        assert!(winprob::samples(&events, None).is_empty());
+        let finished = events_from_rows(r#"[
+            ["1", 1, 12, 0, 4, "", "12:00", null, null, null, "95 - 90", "-5", 0, 0],
+            ["1", 2, 1, 1, 4, "", "6:00", null, null, "Korver Layup", "97 - 90", "-7", 5, 2],
+            ["1", 3, 1, 1, 4, "", "1:00", "Lopez Layup", null, null, "97 - 92", "-5", 4, 1],
+            ["1", 4, 13, 0, 4, "", "0:00", null, null, null, null, null, 0, 0]]"#);
+        let samples = winprob::samples(&finished, None);
+        assert_eq!(samples.len(), 3);
+        assert!(samples.iter().all(|x| !x.home_won));
+        assert_eq!(samples[2].features.seconds_left, 60.0);
+        let flat = WinProbabilityModel {
+            intercept: 0.0,
+            margin: 0.0,
+            margin_by_time: 0.0,
+            possession: 0.0,
+            spread: 0.0,
+        };
+        let fitted = flat.fit(&samples, 50, 0.1);
+        assert!(fitted.intercept < 0.0);
+        assert!(fitted.predict(&Features { margin: -3.0, ..early }) < 0.5);
+        assert_eq!(flat.fit(&[], 50, 0.1), flat);
+
+        let events = events_from_rows(r#"[
+            ["1", 1, 12, 0, 4, "", "12:00", null, null, null, "90 - 91", "1", 0, 0],
+            ["1", 2, 13, 0, 4, "", "0:00", null, null, null, null, null, 0, 0]]"#);
+        let series = winprob::series(&events, &model, None);
+        assert!(series[0].home < 1.0);
+        assert_eq!(series[1].home, 1.0);
+    }
+
+    #[test]
+    fn test_momentum() {
+        let events = playbyplay_events();
+        let found = momentum::momentum(&events, 5);
+        assert_eq!(found.events.len(), 2);
+        match found.events[0] {
+            MomentumEvent::Run { side, points, start, end } => {
+                assert_eq!((side, points), (Side::Visitor, 7));
+                assert_eq!(start.eventnum, Some(3));
+                assert_eq!(end.clock, Some(GameClock::new(10, 35)));
+                assert_eq!(end.score, Score { home: 0, away: 7 });
+            }
+            ref other => panic!("expected a run, got {:?}", other),
+        }
+        match found.events[1] {
+            MomentumEvent::Run { side, points, .. } => {
+                assert_eq!((side, points), (Side::Home, 5));
+            }
+            ref other => panic!("expected a run, got {:?}", other),
+        }
+        assert_eq!(found.lead_changes(), 0);
+        assert_eq!(found.largest_lead(Side::Visitor), 8);
+        assert_eq!(found.largest_home_lead, 0);
+        assert_eq!(found.comeback(), None);
+        assert_eq!(found.score, Score { home: 16, away: 19 });
+
+        let events = events_from_rows(r#"[
+            ["1", 1, 1, 1, 4, "", "3:00", null, null, null, "10 - 0", "-10", 0, 0],
+            ["1", 2, 1, 1, 4, "", "2:00", null, null, null, "10 - 8", "-2", 0, 0],
+            ["1", 3, 4, 0, 4, "", "1:50", null, null, null, null, null, 0, 0],
+            ["1", 4, 1, 1, 4, "", "1:40", null, null, null, "10 - 10", "TIE", 0, 0],
+            ["1", 5, 1, 1, 4, "", "1:00", null, null, null, "10 - 13", "3", 0, 0],
+            ["1", 6, 1, 1, 4, "", "0:30", null, null, null, "12 - 13", "1", 0, 0]]"#);
+        let found = momentum::momentum(&events, 10);
+        assert_eq!(found.ties(), 1);
+        assert_eq!(found.lead_changes(), 1);
+        match found.events[2] {
+            MomentumEvent::LeadChange { side, at } => {
+                assert_eq!(side, Side::Home);
+                assert_eq!(at.eventnum, Some(5));
+            }
+            ref other => panic!("expected a lead change, got {:?}", other),
+        }
+        match found.events[3] {
+            MomentumEvent::Run { side, points, start, .. } => {
+                assert_eq!((side, points), (Side::Home, 13));
+                assert_eq!(start.eventnum, Some(2));
+            }
+            ref other => panic!("expected a run, got {:?}", other),
+        }
+        assert_eq!(found.events.len(), 4);
+        assert_eq!(found.comeback(), Some((Side::Home, 10)));
+
+        // The home six points are corrected down to four, then a late row catches up on a
+        // basket by each side.
+        let events = events_from_rows(r#"[
+            ["1", 1, 1, 1, 4, "", "3:00", null, null, null, "0 - 6", "6", 0, 0],
+            ["1", 2, 18, 0, 4, "", "2:50", null, null, null, "0 - 4", "4", 0, 0],
+            ["1", 3, 1, 1, 4, "", "2:00", null, null, null, "2 - 6", "4", 0, 0]]"#);
+        let found = momentum::momentum(&events, 4);
+        assert_eq!(found.events.len(), 1);
+        match found.events[0] {
+            MomentumEvent::Run { side, points, start, end } => {
+                assert_eq!((side, points), (Side::Home, 8));
+                assert_eq!(start.eventnum, Some(1));
+                assert_eq!(end.eventnum, Some(3));
+            }
+            ref other => panic!("expected a run, got {:?}", other),
+        }
+        assert_eq!(found.largest_home_lead, 6);
+        assert_eq!(found.score, Score { home: 6, away: 2 });
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Shot {
+        player_id: String,
+        made: bool,
+        distance: Option<u64>,
+        margin: Option<i64>,
+    }
+
+    from_row!(Shot {
+        player_id => "PLAYER_ID",
+        made => "SHOT_MADE_FLAG",
+        distance => "SHOT_DISTANCE",
+        margin => "MARGIN",
+    });
+
+    #[test]
+    fn test_decode_rows() {
+        let data: Value = serde_json::from_str(r#"{
+            "headers": ["PLAYER_ID", "SHOT_MADE_FLAG", "SHOT_DISTANCE", "MARGIN"],
+            "rowSet": [[2544, 1, 24.0, "-4"], ["2544", 0, null, "  "]]
+        }"#)
+                              .unwrap();
+        let headers = data.find("headers").unwrap().as_array().unwrap();
+        let rows = data.find("rowSet").unwrap().as_array().unwrap();
+        let shots: Vec<Shot> = decode_rows(headers, rows).unwrap();
+        assert_eq!(shots[0],
+                   Shot {
+                       player_id: "2544".to_owned(),
+                       made: true,
+                       distance: Some(24),
+                       margin: Some(-4),
+                   });
+        assert_eq!(shots[1].distance, None);
+        assert_eq!(shots[1].margin, None);
+
+        let data: Value = serde_json::from_str(r#"{
+            "headers": ["PLAYER_ID", "SHOT_MADE_FLAG", "SHOT_DISTANCE"],
+            "rowSet": [[2544, 1, 24]]
+        }"#)
+                              .unwrap();
+        let headers = data.find("headers").unwrap().as_array().unwrap();
+        let rows = data.find("rowSet").unwrap().as_array().unwrap();
+        match decode_rows::<Shot>(headers, rows) {
+            Err(NBAError::HeaderMissingError("MARGIN")) => {}
+            other => panic!("expected a missing MARGIN column, got {:?}", other),
+        }
+
+        let data: Value = serde_json::from_str(r#"{
+            "headers": ["PLAYER_ID", "SHOT_MADE_FLAG", "SHOT_DISTANCE", "MARGIN"],
+            "rowSet": [[2544, 1, "long", 0]]
+        }"#)
+                              .unwrap();
+        let headers = data.find("headers").unwrap().as_array().unwrap();
+        let rows = data.find("rowSet").unwrap().as_array().unwrap();
+        match decode_rows::<Shot>(headers, rows) {
+            Err(NBAError::ColumnTypeError { column: "SHOT_DISTANCE", .. }) => {}
+            other => panic!("expected a bad SHOT_DISTANCE, got {:?}", other),
+        }
+    }
+
 }
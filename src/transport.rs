@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::PathBuf;
+use hyper::{Client, Url};
+use hyper::header::Headers;
+use err::NBAError;
+
+
+/// Raw response handed back by a `Transport`.
+#[derive(Debug, Clone)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Sends a fully-built query url along with its headers, and returns the raw response.
+pub trait Transport {
+    fn send(&self, url: &Url, headers: &[(String, String)]) -> Result<Response, NBAError>;
+}
+
+
+/// Default transport. Talks to stats.nba.com over hyper.
+pub struct HyperTransport {
+    client: Client,
+}
+
+impl HyperTransport {
+    pub fn new() -> HyperTransport {
+        HyperTransport { client: Client::new() }
+    }
+}
+
+impl Transport for HyperTransport {
+    fn send(&self, url: &Url, headers: &[(String, String)]) -> Result<Response, NBAError> {
+        let mut raw_headers = Headers::new();
+        for &(ref name, ref value) in headers {
+            raw_headers.set_raw(name.clone(), vec![value.clone().into_bytes()]);
+        }
+
+        let mut response = try!(self.client
+                                    .get(url.clone())
+                                    .headers(raw_headers)
+                                    .send());
+
+        let body: String = {
+            let mut s = String::new();
+            try!(response.read_to_string(&mut s));
+            s
+        };
+
+        Ok(Response {
+            status: response.status.to_u16(),
+            body: body,
+        })
+    }
+}
+
+
+/// Serves canned responses kept in memory, keyed by endpoint url (the query string is ignored).
+pub struct MemoryTransport {
+    responses: HashMap<String, Response>,
+}
+
+impl MemoryTransport {
+    pub fn new() -> MemoryTransport {
+        MemoryTransport { responses: HashMap::new() }
+    }
+
+    pub fn insert(&mut self, base_url: &str, body: &str) {
+        self.insert_response(base_url,
+                             Response {
+                                 status: 200,
+                                 body: body.to_owned(),
+                             });
+    }
+
+    pub fn insert_response(&mut self, base_url: &str, response: Response) {
+        self.responses.insert(base_url.to_owned(), response);
+    }
+}
+
+impl Transport for MemoryTransport {
+    fn send(&self, url: &Url, _headers: &[(String, String)]) -> Result<Response, NBAError> {
+        let key = endpoint_url(url);
+        self.responses
+            .get(&key)
+            .cloned()
+            .ok_or(NBAError::MissingResponse(key))
+    }
+}
+
+
+/// Serves canned responses from a directory, one `<endpoint>.json` file per endpoint
+/// (e.g. `playbyplay.json` for `PLAYBYPLAY_BASE_URL`). The query string is ignored.
+pub struct FileTransport {
+    root: PathBuf,
+}
+
+impl FileTransport {
+    pub fn new<P: Into<PathBuf>>(root: P) -> FileTransport {
+        FileTransport { root: root.into() }
+    }
+}
+
+impl Transport for FileTransport {
+    fn send(&self, url: &Url, _headers: &[(String, String)]) -> Result<Response, NBAError> {
+        let endpoint = url.path()
+                          .split('/')
+                          .filter(|x| !x.is_empty())
+                          .last()
+                          .unwrap_or("index");
+        let path = self.root.join(format!("{}.json", endpoint));
+        if !path.exists() {
+            return Err(NBAError::MissingResponse(url.as_str().to_owned()));
+        }
+        let mut file = try!(File::open(&path));
+        let mut body = String::new();
+        try!(file.read_to_string(&mut body));
+        Ok(Response {
+            status: 200,
+            body: body,
+        })
+    }
+}
+
+
+fn endpoint_url(url: &Url) -> String {
+    let mut url = url.clone();
+    url.set_query(None);
+    url.as_str().to_owned()
+}
@@ -0,0 +1,252 @@
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::Path;
+use serde_json;
+use serde_json::Value;
+use clock::{GameClock, REGULATION_SECONDS};
+use err::NBAError;
+use replay::{Replay, GameState, Side};
+use stats::PlayByPlayEvent;
+
+
+/// What the model sees of a game at one moment, all from the home team's side.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Features {
+    /// Home points minus away points.
+    pub margin: f64,
+    /// Seconds left in regulation, or in the current overtime.
+    pub seconds_left: f64,
+    /// 1 when the home team has the ball, -1 for the visitors, 0 when nobody does.
+    pub possession: f64,
+    /// The pre-game expected home margin, e.g. 5.5 when the home team is favored by 5.5.
+    pub spread: f64,
+}
+
+impl Features {
+    pub fn from_state(state: &GameState, spread: Option<f64>) -> Features {
+        let seconds_left = match state.clock {
+            Some(clock) => clock.game_remaining(state.period),
+            None => REGULATION_SECONDS,
+        };
+        Features {
+            margin: state.score.home as f64 - state.score.away as f64,
+            seconds_left: seconds_left as f64,
+            possession: match state.possession {
+                Some(Side::Home) => 1.0,
+                Some(Side::Visitor) => -1.0,
+                None => 0.0,
+            },
+            spread: spread.unwrap_or(0.0),
+        }
+    }
+
+    /// The model's inputs. A lead matters more as time runs out, and the spread matters
+    /// less.
+    fn inputs(&self) -> [f64; 4] {
+        let minutes_left = self.seconds_left / 60.0;
+        [self.margin,
+         self.margin / (minutes_left + 1.0).sqrt(),
+         self.possession,
+         self.spread * self.seconds_left / REGULATION_SECONDS as f64]
+    }
+}
+
+
+/// A logistic model of the home team's chances of winning.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WinProbabilityModel {
+    pub intercept: f64,
+    pub margin: f64,
+    pub margin_by_time: f64,
+    pub possession: f64,
+    pub spread: f64,
+}
+
+/// Keys of the coefficients in a saved model.
+static KEYS: &'static [&'static str] = &["intercept", "margin", "margin_by_time",
+                                         "possession", "spread"];
+
+impl WinProbabilityModel {
+    /// Rough coefficients to start from until a model is fit.
+    pub fn new() -> WinProbabilityModel {
+        WinProbabilityModel {
+            intercept: 0.0,
+            margin: 0.05,
+            margin_by_time: 0.35,
+            possession: 0.1,
+            spread: 0.1,
+        }
+    }
+
+    fn coefficients(&self) -> [f64; 5] {
+        [self.intercept, self.margin, self.margin_by_time, self.possession, self.spread]
+    }
+
+    fn from_coefficients(c: &[f64; 5]) -> WinProbabilityModel {
+        WinProbabilityModel {
+            intercept: c[0],
+            margin: c[1],
+            margin_by_time: c[2],
+            possession: c[3],
+            spread: c[4],
+        }
+    }
+
+    /// The home team's chance of winning.
+    pub fn predict(&self, features: &Features) -> f64 {
+        let c = self.coefficients();
+        let inputs = features.inputs();
+        let z = c[0] + (0..4).map(|i| c[i + 1] * inputs[i]).fold(0.0, |acc, x| acc + x);
+        1.0 / (1.0 + (-z).exp())
+    }
+
+    /// Fits the model to moments from past games by gradient descent on the log loss,
+    /// starting from `self`.
+    pub fn fit(&self, samples: &[Sample], iterations: usize, rate: f64) -> WinProbabilityModel {
+        if samples.is_empty() {
+            return *self;
+        }
+        let mut c = self.coefficients();
+        for _ in 0..iterations {
+            let model = WinProbabilityModel::from_coefficients(&c);
+            let mut gradient = [0.0; 5];
+            for sample in samples {
+                let error = model.predict(&sample.features) -
+                            if sample.home_won { 1.0 } else { 0.0 };
+                let inputs = sample.features.inputs();
+                gradient[0] += error;
+                for i in 0..4 {
+                    gradient[i + 1] += error * inputs[i];
+                }
+            }
+            for i in 0..5 {
+                c[i] -= rate * gradient[i] / samples.len() as f64;
+            }
+        }
+        WinProbabilityModel::from_coefficients(&c)
+    }
+
+    /// Reads coefficients saved by `to_json`, like
+    /// `{"intercept": 0.0, "margin": 0.05, "margin_by_time": 0.35, ...}`.
+    pub fn from_json(json: &str) -> Result<WinProbabilityModel, NBAError> {
+        let data: Value = try!(serde_json::from_str(json));
+        let data = try!(data.as_object().ok_or(NBAError::ObjectError));
+        let mut c = [0.0; 5];
+        for (i, key) in KEYS.iter().enumerate() {
+            let value = data.get(*key).and_then(|x| x.as_f64());
+            c[i] = try!(value.ok_or(NBAError::MissingField(*key)));
+        }
+        Ok(WinProbabilityModel::from_coefficients(&c))
+    }
+
+    pub fn to_json(&self) -> Result<String, NBAError> {
+        let mut data = BTreeMap::new();
+        for (key, value) in KEYS.iter().zip(self.coefficients().iter()) {
+            data.insert(key.to_string(), Value::F64(*value));
+        }
+        Ok(try!(serde_json::to_string(&Value::Object(data))))
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<WinProbabilityModel, NBAError> {
+        let mut file = try!(File::open(path));
+        let mut s = String::new();
+        try!(file.read_to_string(&mut s));
+        WinProbabilityModel::from_json(&s)
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), NBAError> {
+        let mut file = try!(File::create(path));
+        try!(file.write_all(try!(self.to_json()).as_bytes()));
+        Ok(())
+    }
+}
+
+
+/// A moment from a finished game, for fitting.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sample {
+    pub features: Features,
+    pub home_won: bool,
+}
+
+/// Whether regulation or an overtime has ended with a leader.
+fn is_final(state: &GameState) -> bool {
+    state.period >= 4 && state.clock == Some(GameClock::new(0, 0)) &&
+    state.possession.is_none() && state.score.home != state.score.away
+}
+
+/// Every moment of a finished game before the final buzzer, labeled with its result.
+/// Returns nothing unless the last event ends the game. The final state is left out, since
+/// the game is already decided there.
+pub fn samples(events: &[PlayByPlayEvent], spread: Option<f64>) -> Vec<Sample> {
+    let states: Vec<GameState> = Replay::new(events).map(|(_, state)| state).collect();
+    let (home_won, states) = match states.split_last() {
+        Some((last, rest)) if is_final(last) => (last.score.home > last.score.away, rest),
+        _ => return Vec::new(),
+    };
+    states.iter()
+          .map(|x| {
+              Sample {
+                  features: Features::from_state(x, spread),
+                  home_won: home_won,
+              }
+          })
+          .collect()
+}
+
+
+/// The home team's chance of winning right after an event.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WinProbability {
+    pub eventnum: Option<u64>,
+    pub period: u64,
+    pub clock: Option<GameClock>,
+    pub margin: i64,
+    pub home: f64,
+    /// Change from the previous event, for finding the biggest swings.
+    pub change: f64,
+}
+
+impl WinProbability {
+    pub fn visitor(&self) -> f64 {
+        1.0 - self.home
+    }
+}
+
+/// Win probabilities at every event of a game. Once regulation or an overtime ends with
+/// a leader, the game is decided.
+pub fn series(events: &[PlayByPlayEvent],
+              model: &WinProbabilityModel,
+              spread: Option<f64>)
+              -> Vec<WinProbability> {
+    let mut series: Vec<WinProbability> = Vec::new();
+    for (event, state) in Replay::new(events) {
+        let margin = state.score.home as i64 - state.score.away as i64;
+        let home = if is_final(&state) {
+            if margin > 0 { 1.0 } else { 0.0 }
+        } else {
+            model.predict(&Features::from_state(&state, spread))
+        };
+        let change = series.last().map(|x| home - x.home).unwrap_or(0.0);
+        series.push(WinProbability {
+            eventnum: event.eventnum,
+            period: state.period,
+            clock: state.clock,
+            margin: margin,
+            home: home,
+            change: change,
+        });
+    }
+    series
+}
+
+/// The event that moved the home team's chances the most, either way.
+pub fn biggest_swing(series: &[WinProbability]) -> Option<&WinProbability> {
+    series.iter().fold(None, |best: Option<&WinProbability>, x| {
+        match best {
+            Some(best) if best.change.abs() >= x.change.abs() => Some(best),
+            _ => Some(x),
+        }
+    })
+}